@@ -41,8 +41,12 @@ fn render(frame: &mut Frame) -> Result<()> {
         .lines(vec!["Centered".red().into()])
         .build()?;
 
-    use Constraint::*;
-    let [top, middle, bottom] = Layout::vertical([Length(4); 3]).areas(frame.size());
+    let [top, middle, bottom] = Layout::vertical([
+        left.height_constraint(),
+        right.height_constraint(),
+        centered.height_constraint(),
+    ])
+    .areas(frame.size());
 
     frame.render_widget(left, top);
     frame.render_widget(right, middle);
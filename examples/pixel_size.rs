@@ -53,12 +53,30 @@ fn render(frame: &mut Frame) -> Result<()> {
         .lines(vec!["Sextant".cyan().into(), " 1/2*1/3".cyan().into()])
         .build()?;
 
-    // Setup layout for 6 blocks
+    let braille_text = BigText::builder()
+        .pixel_size(PixelSize::Braille)
+        .lines(vec![
+            "Braille".magenta().into(),
+            " 1/2*1/4".magenta().into(),
+        ])
+        .build()?;
+
+    let octant_text = BigText::builder()
+        .pixel_size(PixelSize::Octant)
+        .lines(vec![
+            "Octant".light_blue().into(),
+            " 1/2*1/4".light_blue().into(),
+        ])
+        .build()?;
+
+    // Setup layout for 8 blocks
     use Constraint::*;
-    let [full, half_height, middle, bottom] =
-        Layout::vertical([Length(8), Length(4), Length(8), Length(6)]).areas(frame.size());
+    let [full, half_height, middle, bottom, eighths] =
+        Layout::vertical([Length(8), Length(4), Length(8), Length(6), Length(4)])
+            .areas(frame.size());
     let [half_wide, quadrant] = Layout::horizontal([Length(32), Length(32)]).areas(middle);
     let [third_height, sextant] = Layout::horizontal([Length(32), Length(32)]).areas(bottom);
+    let [braille, octant] = Layout::horizontal([Length(32), Length(32)]).areas(eighths);
 
     frame.render_widget(full_size_text, full);
     frame.render_widget(half_height_text, half_height);
@@ -66,6 +84,8 @@ fn render(frame: &mut Frame) -> Result<()> {
     frame.render_widget(quadrant_text, quadrant);
     frame.render_widget(third_text, third_height);
     frame.render_widget(sextant_text, sextant);
+    frame.render_widget(braille_text, braille);
+    frame.render_widget(octant_text, octant);
 
     Ok(())
 }
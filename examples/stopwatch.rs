@@ -142,6 +142,7 @@ impl StopwatchApp {
         BigText::builder()
             .lines(lines)
             .style(style)
+            .auto_fit(true)
             .build()
             .unwrap()
     }
@@ -186,11 +187,11 @@ impl StopwatchApp {
 
 fn layout(area: Rect) -> Vec<Rect> {
     let layout = Layout::vertical(vec![
-        Constraint::Length(2), // top bar
-        Constraint::Length(8), // timer
-        Constraint::Length(1), // splits header
-        Constraint::Min(0),    // splits
-        Constraint::Length(1), // help
+        Constraint::Length(2),      // top bar
+        Constraint::Percentage(50), // timer
+        Constraint::Length(1),      // splits header
+        Constraint::Min(0),         // splits
+        Constraint::Length(1),      // help
     ])
     .split(area);
     let top_layout = Layout::horizontal(vec![
@@ -0,0 +1,715 @@
+use std::{borrow::Cow, collections::HashMap, error::Error, fmt, str::FromStr};
+
+use crate::font::{Font, FontMetrics, Glyph};
+
+/// A bitmap font loaded from a [Glyph Bitmap Distribution Format][BDF] (`.bdf`) file.
+///
+/// BDF is the bitmap font format used by X11, and many popular bitmap fonts (Terminus, unifont,
+/// Spleen, etc.) are distributed in it. Glyphs can have any size.
+///
+/// The glyph cell is as tall as the font's ascent plus descent. Its width is the most common
+/// advance width (`DWIDTH`) of the glyphs in the font, which falls back to the width of the
+/// `FONTBOUNDINGBOX` if no glyph defines an advance width. Glyphs with a different advance width
+/// keep their own width.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tui_big_text::{BdfFont, BigText};
+///
+/// let source = std::fs::read_to_string("/usr/share/fonts/misc/ter-u16n.bdf")?;
+/// let font = BdfFont::parse(&source)?;
+/// let big_text = BigText::builder()
+///     .font(&font)
+///     .lines(vec!["Terminus".into()])
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [BDF]: https://en.wikipedia.org/wiki/Glyph_Bitmap_Distribution_Format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BdfFont {
+    metrics: FontMetrics,
+    glyphs: HashMap<char, Glyph>,
+}
+
+impl BdfFont {
+    /// Parses a font from the contents of a BDF file.
+    ///
+    /// Glyphs without a Unicode encoding (`ENCODING -1`) are skipped.
+    pub fn parse(source: &str) -> Result<Self, BdfError> {
+        Parser::new(source).parse()
+    }
+
+    /// The number of glyphs in the font.
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Returns whether the font has no glyphs.
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+}
+
+impl FromStr for BdfFont {
+    type Err = BdfError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+impl Font for BdfFont {
+    fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>> {
+        let mut chars = grapheme.chars();
+        let c = chars.next()?;
+        if chars.next().is_some() {
+            return None;
+        }
+        self.glyphs.get(&c).map(Cow::Borrowed)
+    }
+
+    fn metrics(&self) -> FontMetrics {
+        self.metrics
+    }
+}
+
+/// An error that occurred while parsing a BDF font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BdfError {
+    line: usize,
+    kind: BdfErrorKind,
+}
+
+impl BdfError {
+    /// The line number (starting at 1) where the error occurred.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The kind of error that occurred.
+    pub const fn kind(&self) -> &BdfErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for BdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl Error for BdfError {}
+
+/// The kind of a [`BdfError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BdfErrorKind {
+    /// The file ended before the font was complete.
+    UnexpectedEof,
+    /// A keyword was found where a different one was expected.
+    UnexpectedKeyword {
+        /// The keyword that was expected.
+        expected: &'static str,
+        /// The keyword that was found.
+        found: String,
+    },
+    /// A required keyword was not found before it was needed.
+    MissingKeyword(&'static str),
+    /// The value of a keyword could not be parsed.
+    InvalidValue {
+        /// The keyword whose value is invalid.
+        keyword: &'static str,
+        /// The invalid value.
+        value: String,
+    },
+    /// A row of a glyph bitmap is not valid hexadecimal data.
+    InvalidBitmap(String),
+}
+
+impl fmt::Display for BdfErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => write!(f, "unexpected end of file"),
+            Self::UnexpectedKeyword { expected, found } => {
+                write!(f, "expected `{expected}`, found `{found}`")
+            }
+            Self::MissingKeyword(keyword) => write!(f, "missing `{keyword}`"),
+            Self::InvalidValue { keyword, value } => {
+                write!(f, "invalid value for `{keyword}`: `{value}`")
+            }
+            Self::InvalidBitmap(row) => write!(f, "invalid bitmap row `{row}`"),
+        }
+    }
+}
+
+/// A bounding box as defined by the `FONTBOUNDINGBOX` and `BBX` keywords.
+#[derive(Debug, Clone, Copy)]
+struct BoundingBox {
+    width: u16,
+    height: u16,
+    x_offset: i32,
+    y_offset: i32,
+}
+
+impl fmt::Display for BoundingBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.width, self.height, self.x_offset, self.y_offset
+        )
+    }
+}
+
+/// A vertical font metric, with the keyword, value and line it was defined by so that errors
+/// can point at the definition.
+struct Metric {
+    value: i32,
+    keyword: &'static str,
+    source: String,
+    line: usize,
+}
+
+impl Metric {
+    fn invalid(&self) -> BdfError {
+        Parser::invalid_value_at(self.line, self.keyword, &self.source)
+    }
+}
+
+/// A glyph as it appears in the file, before it is placed into the glyph cell of the font.
+#[derive(Debug)]
+struct RawGlyph {
+    encoding: Option<char>,
+    advance: Option<u16>,
+    bounding_box: BoundingBox,
+    /// The line of the `BBX` keyword.
+    line: usize,
+    rows: Vec<Vec<bool>>,
+}
+
+struct Parser<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            lines: source.lines().enumerate(),
+            line: 0,
+        }
+    }
+
+    fn parse(mut self) -> Result<BdfFont, BdfError> {
+        self.expect("STARTFONT")?;
+        let mut bounding_box = None;
+        let mut ascent = None;
+        let mut descent = None;
+        loop {
+            let (keyword, value) = self.next_line()?;
+            match keyword {
+                "FONTBOUNDINGBOX" => {
+                    bounding_box = Some((self.bounding_box("FONTBOUNDINGBOX", value)?, self.line));
+                }
+                "FONT_ASCENT" => ascent = Some(self.metric("FONT_ASCENT", value)?),
+                "FONT_DESCENT" => descent = Some(self.metric("FONT_DESCENT", value)?),
+                "CHARS" => break,
+                _ => {}
+            }
+        }
+        let (bounding_box, bounding_box_line) =
+            bounding_box.ok_or(self.error(BdfErrorKind::MissingKeyword("FONTBOUNDINGBOX")))?;
+
+        let mut raw_glyphs = Vec::new();
+        loop {
+            let (keyword, _) = self.next_line()?;
+            match keyword {
+                "STARTCHAR" => raw_glyphs.push(self.raw_glyph()?),
+                "ENDFONT" => break,
+                _ => return Err(self.unexpected("STARTCHAR", keyword)),
+            }
+        }
+
+        // metrics that are missing fall back to the font bounding box
+        let from_bounding_box = |value: Option<i32>| {
+            let source = bounding_box.to_string();
+            match value {
+                Some(value) => Ok(Metric {
+                    value,
+                    keyword: "FONTBOUNDINGBOX",
+                    source,
+                    line: bounding_box_line,
+                }),
+                None => Err(Self::invalid_value_at(
+                    bounding_box_line,
+                    "FONTBOUNDINGBOX",
+                    &source,
+                )),
+            }
+        };
+        let ascent = match ascent {
+            Some(ascent) => ascent,
+            None => {
+                from_bounding_box((bounding_box.height as i32).checked_add(bounding_box.y_offset))?
+            }
+        };
+        let descent = match descent {
+            Some(descent) => descent,
+            None => from_bounding_box(bounding_box.y_offset.checked_neg())?,
+        };
+        let height = ascent
+            .value
+            .checked_add(descent.value)
+            .and_then(|height| u16::try_from(height).ok())
+            .ok_or_else(|| descent.invalid())?;
+        let baseline = u16::try_from(ascent.value).map_err(|_| ascent.invalid())?;
+        let ascent = ascent.value;
+        let width = most_common_advance(&raw_glyphs).unwrap_or(bounding_box.width);
+        let mut glyphs = HashMap::new();
+        for raw in raw_glyphs {
+            let Some(c) = raw.encoding else {
+                continue;
+            };
+            let width = raw.advance.unwrap_or(width);
+            let glyph = raw.place(width, height, ascent).ok_or_else(|| {
+                Self::invalid_value_at(raw.line, "BBX", &raw.bounding_box.to_string())
+            })?;
+            glyphs.insert(c, glyph);
+        }
+        Ok(BdfFont {
+            metrics: FontMetrics::new(width, height, baseline),
+            glyphs,
+        })
+    }
+
+    /// Parses the keywords between `STARTCHAR` and `ENDCHAR`.
+    fn raw_glyph(&mut self) -> Result<RawGlyph, BdfError> {
+        let mut encoding = None;
+        let mut advance = None;
+        let mut bounding_box = None;
+        loop {
+            let (keyword, value) = self.next_line()?;
+            match keyword {
+                "ENCODING" => {
+                    let code: i64 = self.number("ENCODING", value)?;
+                    encoding = u32::try_from(code).ok().and_then(char::from_u32);
+                }
+                "DWIDTH" => {
+                    let width: i32 = self.number("DWIDTH", value)?;
+                    advance = u16::try_from(width).ok();
+                }
+                "BBX" => bounding_box = Some((self.bounding_box("BBX", value)?, self.line)),
+                "BITMAP" => break,
+                "ENDCHAR" => return Err(self.unexpected("BITMAP", keyword)),
+                _ => {}
+            }
+        }
+        let (bounding_box, line) =
+            bounding_box.ok_or(self.error(BdfErrorKind::MissingKeyword("BBX")))?;
+        let mut rows = Vec::with_capacity(bounding_box.height as usize);
+        for _ in 0..bounding_box.height {
+            let (row, _) = self.next_line()?;
+            rows.push(self.bitmap_row(row, bounding_box.width)?);
+        }
+        self.expect("ENDCHAR")?;
+        Ok(RawGlyph {
+            encoding,
+            advance,
+            bounding_box,
+            line,
+            rows,
+        })
+    }
+
+    /// Returns the keyword and the rest of the next line, skipping comments and empty lines.
+    fn next_line(&mut self) -> Result<(&'a str, &'a str), BdfError> {
+        for (index, line) in self.lines.by_ref() {
+            self.line = index + 1;
+            let line = line.trim();
+            let (keyword, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            if !keyword.is_empty() && keyword != "COMMENT" {
+                return Ok((keyword, value.trim()));
+            }
+        }
+        Err(self.error(BdfErrorKind::UnexpectedEof))
+    }
+
+    fn expect(&mut self, expected: &'static str) -> Result<&'a str, BdfError> {
+        let (keyword, value) = self.next_line()?;
+        if keyword == expected {
+            Ok(value)
+        } else {
+            Err(self.unexpected(expected, keyword))
+        }
+    }
+
+    fn number<T: FromStr>(&self, keyword: &'static str, value: &str) -> Result<T, BdfError> {
+        value
+            .split_whitespace()
+            .next()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| self.invalid_value(keyword, value))
+    }
+
+    fn metric(&self, keyword: &'static str, value: &str) -> Result<Metric, BdfError> {
+        Ok(Metric {
+            value: self.number(keyword, value)?,
+            keyword,
+            source: value.to_string(),
+            line: self.line,
+        })
+    }
+
+    fn bounding_box(&self, keyword: &'static str, value: &str) -> Result<BoundingBox, BdfError> {
+        let numbers = value
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|_| self.invalid_value(keyword, value))?;
+        match numbers[..] {
+            [width, height, x_offset, y_offset] => Ok(BoundingBox {
+                width: u16::try_from(width).map_err(|_| self.invalid_value(keyword, value))?,
+                height: u16::try_from(height).map_err(|_| self.invalid_value(keyword, value))?,
+                x_offset,
+                y_offset,
+            }),
+            _ => Err(self.invalid_value(keyword, value)),
+        }
+    }
+
+    /// Parses a row of hex encoded bitmap data, where the most significant bit of the first byte
+    /// is the leftmost pixel.
+    fn bitmap_row(&self, row: &str, width: u16) -> Result<Vec<bool>, BdfError> {
+        let invalid = || self.error(BdfErrorKind::InvalidBitmap(row.to_string()));
+        let nibbles = row
+            .chars()
+            .map(|c| c.to_digit(16).ok_or_else(invalid))
+            .collect::<Result<Vec<_>, _>>()?;
+        if nibbles.len() * 4 < width as usize {
+            return Err(invalid());
+        }
+        Ok((0..width as usize)
+            .map(|x| nibbles[x / 4] & (0b1000 >> (x % 4)) != 0)
+            .collect())
+    }
+
+    fn error(&self, kind: BdfErrorKind) -> BdfError {
+        BdfError {
+            line: self.line,
+            kind,
+        }
+    }
+
+    fn unexpected(&self, expected: &'static str, found: &str) -> BdfError {
+        self.error(BdfErrorKind::UnexpectedKeyword {
+            expected,
+            found: found.to_string(),
+        })
+    }
+
+    fn invalid_value(&self, keyword: &'static str, value: &str) -> BdfError {
+        Self::invalid_value_at(self.line, keyword, value)
+    }
+
+    /// An invalid value error for a keyword on an earlier line than the current one.
+    fn invalid_value_at(line: usize, keyword: &'static str, value: &str) -> BdfError {
+        BdfError {
+            line,
+            kind: BdfErrorKind::InvalidValue {
+                keyword,
+                value: value.to_string(),
+            },
+        }
+    }
+}
+
+impl RawGlyph {
+    /// Places the bitmap into a glyph cell of the given size, relative to the origin on the
+    /// baseline. Pixels that fall outside of the cell are dropped.
+    ///
+    /// Returns `None` if the position of the bitmap overflows.
+    fn place(&self, width: u16, height: u16, ascent: i32) -> Option<Glyph> {
+        let mut glyph = Glyph::new(width, height);
+        let left = i64::from(self.bounding_box.x_offset);
+        let top = ascent
+            .checked_sub(self.bounding_box.y_offset)?
+            .checked_sub(self.bounding_box.height.into())?;
+        for (y, row) in (i64::from(top)..).zip(&self.rows) {
+            for (x, &pixel) in (left..).zip(row) {
+                if let (Ok(x), Ok(y)) = (u16::try_from(x), u16::try_from(y)) {
+                    glyph.set_pixel(x, y, pixel);
+                }
+            }
+        }
+        Some(glyph)
+    }
+}
+
+/// Returns the advance width shared by the most glyphs, preferring the narrower on ties.
+fn most_common_advance(glyphs: &[RawGlyph]) -> Option<u16> {
+    let mut counts = HashMap::new();
+    for advance in glyphs.iter().filter_map(|glyph| glyph.advance) {
+        *counts.entry(advance).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(advance, count)| (count, std::cmp::Reverse(advance)))
+        .map(|(advance, _)| advance)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use ratatui::prelude::*;
+
+    use super::*;
+    use crate::BigText;
+
+    type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// A 4x6 font with a descender, two ASCII glyphs and one double width glyph.
+    const SOURCE: &str = indoc! {"
+        STARTFONT 2.1
+        COMMENT A tiny test font
+        FONT -test-tiny-medium-r-normal--6-60-75-75-c-40-iso10646-1
+        SIZE 6 75 75
+        FONTBOUNDINGBOX 4 6 0 -1
+        STARTPROPERTIES 2
+        FONT_ASCENT 5
+        FONT_DESCENT 1
+        ENDPROPERTIES
+        CHARS 4
+        STARTCHAR A
+        ENCODING 65
+        SWIDTH 666 0
+        DWIDTH 4 0
+        BBX 3 5 0 0
+        BITMAP
+        40
+        A0
+        E0
+        A0
+        A0
+        ENDCHAR
+        STARTCHAR j
+        ENCODING 106
+        DWIDTH 4 0
+        BBX 2 5 0 -1
+        BITMAP
+        40
+        00
+        40
+        40
+        80
+        ENDCHAR
+        STARTCHAR uni4E00
+        ENCODING 19968
+        DWIDTH 8 0
+        BBX 8 1 0 2
+        BITMAP
+        FF
+        ENDCHAR
+        STARTCHAR unencoded
+        ENCODING -1
+        DWIDTH 4 0
+        BBX 1 1 0 0
+        BITMAP
+        80
+        ENDCHAR
+        ENDFONT
+    "};
+
+    #[test]
+    fn parse() -> Result<()> {
+        let font = BdfFont::parse(SOURCE)?;
+        assert_eq!(font.metrics(), FontMetrics::new(4, 6, 5));
+        assert_eq!(font.len(), 3);
+        let a = font.glyph("A").ok_or("missing glyph")?;
+        assert_eq!(a.rows(), [".#..", "#.#.", "###.", "#.#.", "#.#.", "...."]);
+        let j = font.glyph("j").ok_or("missing glyph")?;
+        assert_eq!(j.rows(), ["....", ".#..", "....", ".#..", ".#..", "#..."]);
+        let wide = font.glyph("一").ok_or("missing glyph")?;
+        assert_eq!(
+            wide.rows(),
+            ["........", "........", "########", "........", "........", "........",]
+        );
+        assert_eq!(font.glyph("B"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn render() -> Result<()> {
+        let font = BdfFont::parse(SOURCE)?;
+        let big_text = BigText::builder()
+            .font(&font)
+            .lines(vec![Line::from("jAA")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "     █   █  ",
+            " █  █ █ █ █ ",
+            "    ███ ███ ",
+            " █  █ █ █ █ ",
+            " █  █ █ █ █ ",
+            "█           ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn bounding_box_fallback_metrics() -> Result<()> {
+        let font = BdfFont::parse(indoc! {"
+            STARTFONT 2.1
+            FONTBOUNDINGBOX 5 7 0 -2
+            CHARS 0
+            ENDFONT
+        "})?;
+        assert_eq!(font.metrics(), FontMetrics::new(5, 7, 5));
+        assert!(font.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn error_missing_startfont() -> Result<()> {
+        let error = BdfFont::parse("COMMENT nothing here\nFONT foo\n").unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(
+            error.kind(),
+            &BdfErrorKind::UnexpectedKeyword {
+                expected: "STARTFONT",
+                found: "FONT".to_string()
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected `STARTFONT`, found `FONT`"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn error_missing_bounding_box() -> Result<()> {
+        let error = BdfFont::parse("STARTFONT 2.1\nCHARS 0\nENDFONT\n").unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(
+            error.kind(),
+            &BdfErrorKind::MissingKeyword("FONTBOUNDINGBOX")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn error_invalid_value() -> Result<()> {
+        let error = BdfFont::parse("STARTFONT 2.1\nFONTBOUNDINGBOX 8 x 0 0\n").unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(
+            error.to_string(),
+            "line 2: invalid value for `FONTBOUNDINGBOX`: `8 x 0 0`"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn error_overflowing_metrics() -> Result<()> {
+        let source = SOURCE.replace("FONT_ASCENT 5", "FONT_ASCENT 2147483647");
+        let error = BdfFont::parse(&source).unwrap_err();
+        assert_eq!(error.line(), 8);
+        assert_eq!(
+            error.kind(),
+            &BdfErrorKind::InvalidValue {
+                keyword: "FONT_DESCENT",
+                value: "1".to_string(),
+            }
+        );
+        let error = BdfFont::parse(indoc! {"
+            STARTFONT 2.1
+            FONTBOUNDINGBOX 5 7 0 -2147483648
+            CHARS 0
+            ENDFONT
+        "})
+        .unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(
+            error.kind(),
+            &BdfErrorKind::InvalidValue {
+                keyword: "FONTBOUNDINGBOX",
+                value: "5 7 0 -2147483648".to_string(),
+            }
+        );
+        let source = SOURCE.replacen("BBX 2 5 0 -1", "BBX 2 5 0 -2147483648", 1);
+        let error = BdfFont::parse(&source).unwrap_err();
+        assert_eq!(error.line(), 26);
+        assert_eq!(
+            error.kind(),
+            &BdfErrorKind::InvalidValue {
+                keyword: "BBX",
+                value: "2 5 0 -2147483648".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn error_negative_metrics() -> Result<()> {
+        let source = SOURCE.replace("FONT_DESCENT 1", "FONT_DESCENT -10");
+        let error = BdfFont::parse(&source).unwrap_err();
+        assert_eq!(error.line(), 8);
+        assert_eq!(
+            error.kind(),
+            &BdfErrorKind::InvalidValue {
+                keyword: "FONT_DESCENT",
+                value: "-10".to_string(),
+            }
+        );
+        let source = SOURCE
+            .replace("FONT_ASCENT 5", "FONT_ASCENT -1")
+            .replace("FONT_DESCENT 1", "FONT_DESCENT 7");
+        let error = BdfFont::parse(&source).unwrap_err();
+        assert_eq!(error.line(), 7);
+        assert_eq!(
+            error.kind(),
+            &BdfErrorKind::InvalidValue {
+                keyword: "FONT_ASCENT",
+                value: "-1".to_string(),
+            }
+        );
+        let error = BdfFont::parse(indoc! {"
+            STARTFONT 2.1
+            FONTBOUNDINGBOX 5 7 0 -8
+            CHARS 0
+            ENDFONT
+        "})
+        .unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(
+            error.kind(),
+            &BdfErrorKind::InvalidValue {
+                keyword: "FONTBOUNDINGBOX",
+                value: "5 7 0 -8".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn error_invalid_bitmap() -> Result<()> {
+        let source = SOURCE.replacen("E0", "G0", 1);
+        let error = BdfFont::parse(&source).unwrap_err();
+        assert_eq!(error.line(), 19);
+        assert_eq!(error.kind(), &BdfErrorKind::InvalidBitmap("G0".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn error_unexpected_eof() -> Result<()> {
+        let source = &SOURCE[..SOURCE.find("ENDCHAR").unwrap()];
+        let error = BdfFont::parse(source).unwrap_err();
+        assert_eq!(error.line(), 21);
+        assert_eq!(error.kind(), &BdfErrorKind::UnexpectedEof);
+        Ok(())
+    }
+}
@@ -1,18 +1,27 @@
-use std::cmp::min;
+use std::{borrow::Cow, cmp::min, iter, ops::Range};
 
 use derive_builder::Builder;
-use font8x8::UnicodeFonts;
 use ratatui::{prelude::*, text::StyledGrapheme, widgets::Widget};
+use unicode_width::UnicodeWidthStr;
 
-use crate::PixelSize;
+use crate::{
+    font::{grapheme_glyph, Font, FontRef, Glyph},
+    missing_glyph::tofu,
+    FigletFont, MissingGlyph, Overflow, PixelSize, VerticalAlignment,
+};
 
-/// Displays one or more lines of text using 8x8 pixel characters.
+/// Displays one or more lines of text using big pixel glyphs.
 ///
-/// The text is rendered using the [font8x8](https://crates.io/crates/font8x8) crate.
+/// The text is rendered using the [font8x8](https://crates.io/crates/font8x8) crate by default.
+/// Any other glyph set can be used by passing a [`Font`] to the `font` method, and graphemes that
+/// are missing from it are looked up in the fonts passed to the `fallback_font` method.
 ///
 /// Using the `pixel_size` method, you can also chose, how 'big' a pixel should be. Currently a
-/// pixel of the 8x8 font can be represented by one full or half (horizontal/vertical/both)
-/// character cell of the terminal.
+/// pixel of the font can be represented by one full or half (horizontal/vertical/both) character
+/// cell of the terminal. Glyphs of any width and height are supported, so a font with 5x7 glyphs
+/// takes up 3x3 cells with [`PixelSize::Sextant`].
+/// The `scale` method draws each font pixel as a block of pixels, for text that is bigger than
+/// [`PixelSize::Full`].
 ///
 /// # Examples
 ///
@@ -73,63 +82,901 @@ pub struct BigText<'a> {
 
     /// The horizontal alignmnet of the text
     ///
-    /// Defaults to `Alignment::default()` (=> Alignment::Left)
+    /// Lines that have their own alignment, set with [`Line::alignment`], are aligned with that
+    /// instead. Defaults to `Alignment::default()` (=> Alignment::Left)
     #[builder(default)]
     alignment: Alignment,
+
+    /// The vertical alignment of the lines within the area
+    ///
+    /// Defaults to [`VerticalAlignment::Top`]
+    #[builder(default)]
+    vertical_alignment: VerticalAlignment,
+
+    /// Whether every line but the last is justified to span the full width of the area
+    ///
+    /// The space that is left over on a line is spread between its words, or between all of its
+    /// glyphs if it has a single word. Lines that are wider than the area and the last line are
+    /// aligned as usual. Justification is not applied to FIGlet fonts. Defaults to `false`
+    #[builder(default)]
+    justify: bool,
+
+    /// Whether lines that are wider than the area are wrapped onto extra lines
+    ///
+    /// Lines are broken between words, or between graphemes if a word doesn't fit on a line by
+    /// itself, based on the rendered width of the glyphs. Wrapping is not applied to FIGlet fonts.
+    /// Defaults to `false`
+    #[builder(default)]
+    wrap: bool,
+
+    /// What happens to text that doesn't fit in the area
+    ///
+    /// Overflow is not handled for FIGlet fonts. Defaults to [`Overflow::Clip`]
+    #[builder(default)]
+    overflow: Overflow,
+
+    /// The number of pixels of the pixel size that each font pixel is scaled to horizontally
+    ///
+    /// A scale of `0` is treated as `1`. Defaults to `1`
+    #[builder(default = "1")]
+    scale_x: u16,
+
+    /// The number of pixels of the pixel size that each font pixel is scaled to vertically
+    ///
+    /// A scale of `0` is treated as `1`. Defaults to `1`
+    #[builder(default = "1")]
+    scale_y: u16,
+
+    /// Whether the pixel size and scale are chosen automatically, as the combination of the
+    /// `auto_fit_pixel_sizes` and a scale that renders all lines as large as possible within the
+    /// area
+    ///
+    /// When set, `pixel_size`, `scale_x` and `scale_y` are ignored. If the text doesn't fit in the
+    /// area with any of the pixel sizes, the pixel size that renders it smallest is used. Defaults
+    /// to `false`
+    #[builder(default)]
+    auto_fit: bool,
+
+    /// The pixel sizes that are considered when `auto_fit` is set, or when the overflow is
+    /// [`Overflow::Shrink`]
+    ///
+    /// When several combinations render the text equally large, the pixel size that comes first
+    /// is used. Defaults to every pixel size except [`PixelSize::Octant`], whose characters are
+    /// missing from most terminal fonts
+    #[builder(default = "AUTO_FIT_PIXEL_SIZES.to_vec()", setter(into))]
+    auto_fit_pixel_sizes: Vec<PixelSize>,
+
+    /// The font used to look up the glyphs
+    ///
+    /// Defaults to [`Font8x8::ALL`](crate::Font8x8::ALL)
+    #[builder(default, setter(custom))]
+    font: FontRef<'a>,
+
+    /// Whether each glyph is trimmed to the columns that contain set pixels, instead of taking up
+    /// the full width of the font's glyph cell
+    ///
+    /// Glyphs without any set pixels, such as a space, take up half of the glyph cell. Defaults to
+    /// `false`
+    #[builder(default)]
+    proportional: bool,
+
+    /// The number of empty font pixels after each glyph when `proportional` is set, which replaces
+    /// the spacing that is built into the glyph cells of the font
+    ///
+    /// This is in addition to `letter_spacing`, which is added between glyphs whether or not they
+    /// are proportional, so a proportional spacing of `1` and a letter spacing of `1` leave 2
+    /// empty pixels between glyphs. Defaults to `1`
+    #[builder(default = "1")]
+    proportional_spacing: u16,
+
+    /// The number of font pixels added between the glyphs of a line, which can be negative to draw
+    /// them closer together
+    ///
+    /// The spacing is added to kerning and proportional spacing. It is scaled like the glyphs, and
+    /// spacing that is smaller than a cell moves the glyphs into the cells they share with their
+    /// neighbours. Defaults to `0`
+    #[builder(default)]
+    letter_spacing: i16,
+
+    /// The number of font pixels added between lines, which can be negative to make lines
+    /// overlap
+    ///
+    /// The spacing is scaled like the glyphs, and spacing that is smaller than a cell moves the
+    /// lines into the cells they share with their neighbours. Defaults to `0`
+    #[builder(default)]
+    line_spacing: i16,
+
+    /// The fonts that are used, in order, for graphemes that the font has no glyph for
+    ///
+    /// Glyphs from a fallback font are aligned to the baseline of the font. Defaults to no
+    /// fallback fonts
+    #[builder(default, setter(custom))]
+    fallback_fonts: Vec<FontRef<'a>>,
+
+    /// What is rendered for graphemes that neither the font nor the fallback fonts have a glyph
+    /// for
+    ///
+    /// Defaults to [`MissingGlyph::Blank`]
+    #[builder(default)]
+    missing_glyph: MissingGlyph,
+
+    /// The FIGlet font used to render the text as ASCII art instead of pixel glyphs
+    ///
+    /// When set, `font` and `pixel_size` are ignored. Defaults to `None`
+    #[builder(default, setter(strip_option))]
+    figlet_font: Option<&'a FigletFont>,
 }
 
-impl BigText<'static> {
+/// The pixel sizes that are considered by auto fit by default, from the largest to the smallest.
+const AUTO_FIT_PIXEL_SIZES: [PixelSize; 7] = [
+    PixelSize::Full,
+    PixelSize::HalfHeight,
+    PixelSize::HalfWidth,
+    PixelSize::ThirdHeight,
+    PixelSize::Quadrant,
+    PixelSize::Sextant,
+    PixelSize::Braille,
+];
+
+impl<'a> BigText<'a> {
     /// Create a new [`BigTextBuilder`] to configure a [`BigText`] widget.
-    pub fn builder() -> BigTextBuilder<'static> {
+    pub fn builder() -> BigTextBuilder<'a> {
         BigTextBuilder::default()
     }
+
+    /// The number of cells the text takes up when it is rendered in an area that is large
+    /// enough for it.
+    ///
+    /// This accounts for the font, the pixel size, the scale, the width of each glyph including
+    /// proportional spacing, letter spacing and kerning, and the number of lines and the spacing
+    /// between them. With `auto_fit` or `wrap` the size depends on the area, so the size for the
+    /// configured `pixel_size` and `scale` without wrapping is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    /// use tui_big_text::{BigText, PixelSize};
+    ///
+    /// let big_text = BigText::builder()
+    ///     .pixel_size(PixelSize::Quadrant)
+    ///     .lines(vec!["Hello".into()])
+    ///     .build()?;
+    /// assert_eq!(big_text.size(), Size::new(20, 4));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn size(&self) -> Size {
+        let (width, height) = match self.figlet_font {
+            Some(figlet_font) => {
+                let width = self
+                    .lines
+                    .iter()
+                    .map(|line| {
+                        let graphemes = line.styled_graphemes(self.style);
+                        let rows = figlet_font.layout_line(graphemes.map(|g| g.symbol));
+                        rows.first().map_or(0, Vec::len) as u16
+                    })
+                    .max()
+                    .unwrap_or(0);
+                let height = (self.lines.len() as u16).saturating_mul(figlet_font.height());
+                (width, height)
+            }
+            None => {
+                let lines = self.layout_lines();
+                text_size(
+                    self.font.0,
+                    self.pixel_size,
+                    self.scale(),
+                    self.line_spacing,
+                    &lines,
+                    None,
+                )
+            }
+        };
+        Size::new(width, height)
+    }
+
+    /// The number of columns the text takes up, which is the width of the widest line.
+    ///
+    /// See [`BigText::size`] for details.
+    pub fn width(&self) -> u16 {
+        self.size().width
+    }
+
+    /// The number of rows the text takes up.
+    ///
+    /// See [`BigText::size`] for details.
+    pub fn height(&self) -> u16 {
+        self.size().height
+    }
+
+    /// A [`Constraint::Length`] of the width of the text, for use in a horizontal [`Layout`].
+    pub fn width_constraint(&self) -> Constraint {
+        Constraint::Length(self.width())
+    }
+
+    /// A [`Constraint::Length`] of the height of the text, for use in a vertical [`Layout`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    /// use tui_big_text::{BigText, PixelSize};
+    ///
+    /// # fn render(frame: &mut Frame) -> Result<(), Box<dyn std::error::Error>> {
+    /// let big_text = BigText::builder()
+    ///     .pixel_size(PixelSize::Quadrant)
+    ///     .lines(vec!["Title".into()])
+    ///     .build()?;
+    /// let [title, body] =
+    ///     Layout::vertical([big_text.height_constraint(), Constraint::Fill(1)]).areas(frame.size());
+    /// frame.render_widget(big_text, title);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn height_constraint(&self) -> Constraint {
+        Constraint::Length(self.height())
+    }
+}
+
+impl<'a> BigTextBuilder<'a> {
+    /// Scales each font pixel to a `scale`x`scale` block of pixels of the pixel size, which can be
+    /// used to render text larger than [`PixelSize::Full`]
+    ///
+    /// This sets both `scale_x` and `scale_y`. Defaults to `1`
+    pub fn scale(&mut self, scale: u16) -> &mut Self {
+        self.scale_x(scale).scale_y(scale)
+    }
+
+    /// The font used to look up the glyphs
+    ///
+    /// Defaults to [`Font8x8::ALL`](crate::Font8x8::ALL)
+    pub fn font(&mut self, font: &'a dyn Font) -> &mut Self {
+        self.font = Some(FontRef(font));
+        self
+    }
+
+    /// Adds a font to the end of the fallback fonts, which are used in order for graphemes that
+    /// the font has no glyph for
+    ///
+    /// Defaults to no fallback fonts
+    pub fn fallback_font(&mut self, font: &'a dyn Font) -> &mut Self {
+        self.fallback_fonts
+            .get_or_insert_with(Vec::new)
+            .push(FontRef(font));
+        self
+    }
 }
 
 impl Widget for BigText<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let layout = layout(area, &self.pixel_size, self.alignment, &self.lines);
-        for (line, line_layout) in self.lines.iter().zip(layout) {
-            for (g, cell) in line.styled_graphemes(self.style).zip(line_layout) {
-                render_symbol(g, cell, buf, &self.pixel_size);
+        if let Some(figlet_font) = self.figlet_font {
+            render_figlet(
+                &self.lines,
+                self.style,
+                self.alignment,
+                self.vertical_alignment,
+                figlet_font,
+                area,
+                buf,
+            );
+            return;
+        }
+        let font = self.font.0;
+        let lines = self.layout_lines();
+        let (pixel_size, scale) = if self.auto_fit {
+            self.auto_fit_size(area, &lines)
+        } else if self.overflow == Overflow::Shrink {
+            (self.shrink_size(area, &lines), self.scale())
+        } else {
+            (self.pixel_size, self.scale())
+        };
+        let (step_x, _) = pixel_size.pixels_per_cell();
+        let max_width = area.width.saturating_mul(step_x);
+        let pixel_width = pixel_width(scale.0);
+        let lines = if self.wrap {
+            wrap_lines(lines, max_width, pixel_width, self.justify)
+        } else {
+            lines
+        };
+        // declared out here because the ellipsis glyphs borrow it for as long as the lines
+        let ellipsis;
+        let lines = match self.overflow {
+            Overflow::Clip | Overflow::Shrink => lines,
+            Overflow::Ellipsis => {
+                ellipsis = Line::from(if self.find_glyph("…").is_some() {
+                    "…"
+                } else {
+                    "..."
+                });
+                let ellipsis = self.layout_glyphs(&ellipsis);
+                ellipsize_lines(lines, max_width, pixel_width, &ellipsis)
+            }
+            Overflow::Hide => {
+                let max_lines = (0..=lines.len())
+                    .take_while(|&count| {
+                        let height =
+                            text_height(font, pixel_size, scale.1, self.line_spacing, count);
+                        height <= area.height
+                    })
+                    .last()
+                    .unwrap_or(0);
+                hide_overflow(lines, max_width, pixel_width, max_lines)
+            }
+        };
+        let layout = layout(
+            area,
+            font,
+            &pixel_size,
+            scale,
+            self.line_spacing,
+            self.vertical_alignment,
+            &lines,
+        );
+        render_lines(&lines, layout, area, buf, &pixel_size, scale);
+    }
+}
+
+/// The glyphs of a line together with the alignment of the line.
+struct LayoutLine<'a> {
+    glyphs: Vec<LayoutGlyph<'a>>,
+    /// The alignment of the line, or the alignment of the widget if the line has none
+    alignment: Alignment,
+    /// Whether the line is justified to span the full width of the area
+    justify: bool,
+}
+
+/// A grapheme of a line together with its glyph and the columns of the glyph that are drawn.
+#[derive(Clone)]
+struct LayoutGlyph<'a> {
+    grapheme: StyledGrapheme<'a>,
+    glyph: Option<Cow<'a, Glyph>>,
+    /// The font that the glyph was found in, or `None` for placeholders
+    font: Option<FontRef<'a>>,
+    /// The row of the line that the top of the glyph is drawn at, which aligns the baselines of
+    /// glyphs from fallback fonts
+    top: i32,
+    /// Whether the grapheme itself is drawn in the middle of the cell instead of a glyph
+    small: bool,
+    /// The number of empty font pixels before the glyph
+    left: u16,
+    /// The first column of the glyph that is drawn
+    start: u16,
+    /// The number of font pixels the glyph takes up on the line, including any spacing
+    advance: u16,
+    /// The number of font pixels of letter spacing after the glyph
+    spacing: i16,
+}
+
+impl<'a> BigText<'a> {
+    /// Look up the glyphs for each line, which is aligned with its own alignment or else with the
+    /// alignment of the widget, and justified unless it is the last line.
+    fn layout_lines(&self) -> Vec<LayoutLine<'_>> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| LayoutLine {
+                glyphs: self.layout_glyphs(line),
+                alignment: line.alignment.unwrap_or(self.alignment),
+                justify: self.justify && index + 1 < self.lines.len(),
+            })
+            .collect()
+    }
+
+    /// Look up the glyphs for the graphemes of a line and measure how wide each of them is,
+    /// including the letter spacing and the kerning between each pair of glyphs from the same
+    /// font.
+    fn layout_glyphs<'b>(&'b self, line: &'b Line<'b>) -> Vec<LayoutGlyph<'b>> {
+        let font = self.font.0;
+        let mut glyphs = line
+            .styled_graphemes(self.style)
+            .filter_map(|grapheme| {
+                let mut small = false;
+                let (source, mut glyph) = match self.find_glyph(grapheme.symbol) {
+                    Some((source, glyph)) => (Some(source), Some(glyph)),
+                    None if grapheme.symbol.trim().is_empty() => (None, None),
+                    None => match &self.missing_glyph {
+                        MissingGlyph::Blank => (None, None),
+                        MissingGlyph::Tofu => (None, Some(Cow::Owned(tofu(font.metrics())))),
+                        MissingGlyph::Char(c) => {
+                            let glyph = self.find_glyph(c.encode_utf8(&mut [0; 4]));
+                            (None, glyph.map(|(_, glyph)| glyph))
+                        }
+                        MissingGlyph::Glyph(glyph) => (None, Some(Cow::Borrowed(glyph))),
+                        MissingGlyph::Small => {
+                            small = true;
+                            (None, None)
+                        }
+                        MissingGlyph::Skip => return None,
+                    },
+                };
+                let source_font = source.map_or(font, |source| source.0);
+                let top =
+                    i32::from(font.metrics().baseline) - i32::from(source_font.metrics().baseline);
+                let (left, start, advance) = if self.proportional {
+                    match glyph.as_deref().and_then(Glyph::inked_columns) {
+                        Some(columns) => (
+                            0,
+                            columns.start,
+                            columns.len() as u16 + self.proportional_spacing,
+                        ),
+                        None => (0, 0, font.glyph_width().div_ceil(2)),
+                    }
+                } else if grapheme.symbol.width() > 1 {
+                    // wide graphemes take up two cells, so glyphs that fit in one cell are drawn
+                    // twice as wide, and other glyphs that are narrower are centered in them
+                    let advance = source_font.glyph_width().saturating_mul(2);
+                    if let Some(narrow) = glyph
+                        .as_deref()
+                        .filter(|glyph| glyph.width() <= source_font.glyph_width())
+                    {
+                        glyph = Some(Cow::Owned(narrow.double_width()));
+                    }
+                    let width = glyph.as_ref().map_or(advance, |glyph| glyph.width());
+                    (advance.saturating_sub(width) / 2, 0, advance)
+                } else {
+                    (0, 0, source_font.glyph_width())
+                };
+                Some(LayoutGlyph {
+                    grapheme,
+                    glyph,
+                    font: source,
+                    top,
+                    small,
+                    left,
+                    start,
+                    advance,
+                    spacing: 0,
+                })
+            })
+            .collect::<Vec<_>>();
+        for index in 1..glyphs.len() {
+            let (left, right) = (&glyphs[index - 1], &glyphs[index]);
+            let kerning = match (left.font, right.font) {
+                (Some(font), Some(right_font)) if font == right_font => {
+                    font.0.kerning(left.grapheme.symbol, right.grapheme.symbol)
+                }
+                _ => 0,
+            };
+            glyphs[index - 1].advance = glyphs[index - 1].advance.saturating_add_signed(kerning);
+            glyphs[index - 1].spacing = self.letter_spacing;
+        }
+        glyphs
+    }
+
+    /// The horizontal and vertical scale of the font pixels, which is at least `1`.
+    fn scale(&self) -> (u16, u16) {
+        (self.scale_x.max(1), self.scale_y.max(1))
+    }
+
+    /// Choose the pixel size and scale that render the lines as large as possible within the
+    /// area, measured by the number of cells the text takes up.
+    ///
+    /// If the lines don't fit with any of the auto fit pixel sizes, the one that renders them
+    /// smallest is used without scaling.
+    fn auto_fit_size(&self, area: Rect, lines: &[LayoutLine]) -> (PixelSize, (u16, u16)) {
+        let font = self.font.0;
+        let wrap_width = self.wrap.then_some(area.width);
+        let cells = |(width, height): (u16, u16)| u32::from(width) * u32::from(height);
+        let mut best: Option<(u32, PixelSize, u16)> = None;
+        for &pixel_size in &self.auto_fit_pixel_sizes {
+            let (_, step_y) = pixel_size.pixels_per_cell();
+            // the lines are at least one cell high for every `step_y` of scale
+            for scale in 1..=area.height.saturating_mul(step_y) {
+                let size = text_size(
+                    font,
+                    pixel_size,
+                    (scale, scale),
+                    self.line_spacing,
+                    lines,
+                    wrap_width,
+                );
+                if size.0 > area.width || size.1 > area.height {
+                    break;
+                }
+                if best.map_or(true, |(best_cells, ..)| cells(size) > best_cells) {
+                    best = Some((cells(size), pixel_size, scale));
+                }
+            }
+        }
+        if let Some((_, pixel_size, scale)) = best {
+            return (pixel_size, (scale, scale));
+        }
+        self.auto_fit_pixel_sizes
+            .iter()
+            .copied()
+            .min_by_key(|&pixel_size| {
+                let size = text_size(
+                    font,
+                    pixel_size,
+                    (1, 1),
+                    self.line_spacing,
+                    lines,
+                    wrap_width,
+                );
+                cells(size)
+            })
+            .map_or((self.pixel_size, self.scale()), |pixel_size| {
+                (pixel_size, (1, 1))
+            })
+    }
+
+    /// Choose the pixel size of the widget, or else the first of the `auto_fit_pixel_sizes` that
+    /// come after it that fits the lines in the area, or else the last of them.
+    ///
+    /// If the pixel size of the widget is not one of the `auto_fit_pixel_sizes`, all of them are
+    /// tried.
+    fn shrink_size(&self, area: Rect, lines: &[LayoutLine]) -> PixelSize {
+        let font = self.font.0;
+        let wrap_width = self.wrap.then_some(area.width);
+        let sizes = &self.auto_fit_pixel_sizes;
+        let smaller = match sizes.iter().position(|&size| size == self.pixel_size) {
+            Some(index) => &sizes[index + 1..],
+            None => sizes,
+        };
+        let mut smallest = self.pixel_size;
+        for &pixel_size in iter::once(&self.pixel_size).chain(smaller) {
+            let (width, height) = text_size(
+                font,
+                pixel_size,
+                self.scale(),
+                self.line_spacing,
+                lines,
+                wrap_width,
+            );
+            if width <= area.width && height <= area.height {
+                return pixel_size;
             }
+            smallest = pixel_size;
         }
+        smallest
+    }
+
+    /// Look up the glyph for a grapheme in the font, and then in each of the fallback fonts.
+    fn find_glyph(&self, grapheme: &str) -> Option<(FontRef<'a>, Cow<'a, Glyph>)> {
+        iter::once(self.font)
+            .chain(self.fallback_fonts.iter().copied())
+            .find_map(|font| Some((font, grapheme_glyph(font.0, grapheme)?)))
     }
 }
 
-/// Chunk the area into as many x*y cells as possible returned as a 2D iterator of `Rect`s
-/// representing the rows of cells. The size of each cell depends on given font size
-fn layout<'a>(
+/// The cells that a glyph takes up, and the pixel of the area that the left edge of the glyph and
+/// the top of its line are drawn at, which may be partway into the first cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GlyphCell {
     area: Rect,
+    x: u16,
+    y: u16,
+}
+
+/// Chunk the area into cells for each glyph of each line, returned as a 2D iterator of
+/// [`GlyphCell`]s representing the rows of glyphs.
+///
+/// Glyphs and lines are placed at pixel positions of the pixel size, so neighbouring glyphs and
+/// lines may share a cell. The width of each glyph depends on its advance, the letter spacing and
+/// the scale, and the distance between lines depends on the font height, the line spacing and the
+/// scale.
+fn layout<'b>(
+    area: Rect,
+    font: &dyn Font,
     pixel_size: &PixelSize,
-    alignment: Alignment,
-    lines: &'a [Line<'a>],
-) -> impl IntoIterator<Item = impl IntoIterator<Item = Rect>> + 'a {
+    (scale_x, scale_y): (u16, u16),
+    line_spacing: i16,
+    vertical_alignment: VerticalAlignment,
+    lines: &'b [LayoutLine<'b>],
+) -> impl IntoIterator<Item = impl IntoIterator<Item = GlyphCell> + 'b> + 'b {
+    let pixel_size = *pixel_size;
     let (step_x, step_y) = pixel_size.pixels_per_cell();
-    let width = 8_u16.div_ceil(step_x);
-    let height = 8_u16.div_ceil(step_y);
+    let pixel_width = pixel_width(scale_x);
+    let line_height = line_height(font, pixel_size, scale_y).saturating_mul(step_y);
+    let pitch = line_pitch(font, pixel_size, scale_y, line_spacing);
+    let text_height = text_height(font, pixel_size, scale_y, line_spacing, lines.len());
+    let top = vertical_alignment
+        .offset(area.height, text_height)
+        .saturating_mul(step_y);
+    let bottom = area.height.saturating_mul(step_y);
 
-    (area.top()..area.bottom())
-        .step_by(height as usize)
-        .zip(lines.iter())
+    lines
+        .iter()
+        .zip(0u16..)
+        .map(move |(line, index)| (top.saturating_add(index.saturating_mul(pitch)), line))
+        .take_while(move |&(y, _)| y < bottom)
         .map(move |(y, line)| {
-            let offset = get_alignment_offset(area.width, width, alignment, line);
-            (area.left() + offset..area.right())
-                .step_by(width as usize)
-                .map(move |x| {
-                    let width = min(area.right() - x, width);
-                    let height = min(area.bottom() - y, height);
-                    Rect::new(x, y, width, height)
+            let cell_top = area.top() + y / step_y;
+            let cell_height = (y % step_y + line_height)
+                .div_ceil(step_y)
+                .clamp(1, area.bottom() - cell_top);
+            let big_line_width = line_width(&line.glyphs, pixel_size, scale_x);
+            let extra = area.width.saturating_sub(big_line_width);
+            let spacing = line
+                .justify
+                .then(|| justify_spacing(&line.glyphs, extra))
+                .flatten();
+            let offset = match spacing {
+                Some(_) => 0,
+                None => get_alignment_offset(area.width, big_line_width, line.alignment),
+            };
+            let spacing = spacing.unwrap_or_else(|| vec![0; line.glyphs.len()]);
+            line.glyphs
+                .iter()
+                .zip(spacing)
+                .scan(offset.saturating_mul(step_x), move |x, (glyph, spacing)| {
+                    let left = *x;
+                    let right = left.saturating_add(pixel_width(glyph));
+                    *x = right.saturating_add(spacing.saturating_mul(step_x));
+                    Some((left, right))
+                })
+                .take_while(move |&(left, _)| left / step_x < area.width)
+                .map(move |(left, right)| {
+                    let cell_left = area.left() + left / step_x;
+                    let cell_right = min(
+                        area.right(),
+                        area.left().saturating_add(right.div_ceil(step_x)),
+                    );
+                    GlyphCell {
+                        area: Rect::new(cell_left, cell_top, cell_right - cell_left, cell_height),
+                        x: left,
+                        y,
+                    }
                 })
         })
 }
 
-fn get_alignment_offset<'a>(
-    area_width: u16,
-    letter_width: u16,
-    alignment: Alignment,
-    line: &'a Line<'a>,
+/// The number of cells each line is high without line spacing, which depends on the font height,
+/// the vertical scale and the pixel size.
+fn line_height(font: &dyn Font, pixel_size: PixelSize, scale_y: u16) -> u16 {
+    let (_, step_y) = pixel_size.pixels_per_cell();
+    font.glyph_height()
+        .saturating_mul(scale_y)
+        .div_ceil(step_y)
+        .max(1)
+}
+
+/// The number of pixels of the pixel size between the tops of consecutive lines, which is the line
+/// height plus the scaled line spacing.
+fn line_pitch(font: &dyn Font, pixel_size: PixelSize, scale_y: u16, line_spacing: i16) -> u16 {
+    let (_, step_y) = pixel_size.pixels_per_cell();
+    let height = i32::from(line_height(font, pixel_size, scale_y)) * i32::from(step_y);
+    let pitch = height + i32::from(line_spacing) * i32::from(scale_y);
+    u16::try_from(pitch.max(0)).unwrap_or(u16::MAX)
+}
+
+/// The number of cells the given number of lines are high, which depends on the line height and
+/// the line spacing between them.
+fn text_height(
+    font: &dyn Font,
+    pixel_size: PixelSize,
+    scale_y: u16,
+    line_spacing: i16,
+    lines: usize,
 ) -> u16 {
-    let big_line_width = line.width() as u16 * letter_width;
+    if lines == 0 {
+        return 0;
+    }
+    let (_, step_y) = pixel_size.pixels_per_cell();
+    let lines = u16::try_from(lines).unwrap_or(u16::MAX);
+    let line_height = line_height(font, pixel_size, scale_y);
+    (lines - 1)
+        .saturating_mul(line_pitch(font, pixel_size, scale_y, line_spacing))
+        .saturating_add(line_height.saturating_mul(step_y))
+        .div_ceil(step_y)
+}
+
+/// Returns a function that gives the number of pixels of the pixel size a glyph is wide, which is
+/// its advance plus its letter spacing, scaled horizontally, and at least one pixel.
+fn pixel_width(scale_x: u16) -> impl Fn(&LayoutGlyph) -> u16 + Copy {
+    move |glyph| {
+        let width = (i32::from(glyph.advance) + i32::from(glyph.spacing)) * i32::from(scale_x);
+        u16::try_from(width.max(1)).unwrap_or(u16::MAX)
+    }
+}
+
+/// The number of pixels of the pixel size a line is wide.
+fn line_pixels(line: &[LayoutGlyph], pixel_width: impl Fn(&LayoutGlyph) -> u16) -> u16 {
+    line.iter().map(pixel_width).fold(0, u16::saturating_add)
+}
+
+/// The number of cells a line is wide, which depends on the advance of each glyph, the horizontal
+/// scale and the pixel size.
+fn line_width(line: &[LayoutGlyph], pixel_size: PixelSize, scale_x: u16) -> u16 {
+    let (step_x, _) = pixel_size.pixels_per_cell();
+    line_pixels(line, pixel_width(scale_x)).div_ceil(step_x)
+}
+
+/// The number of cells (width, height) that the lines take up, without truncating them to an
+/// area, when they are wrapped to the given number of cells or not wrapped at all.
+fn text_size(
+    font: &dyn Font,
+    pixel_size: PixelSize,
+    (scale_x, scale_y): (u16, u16),
+    line_spacing: i16,
+    lines: &[LayoutLine],
+    wrap_width: Option<u16>,
+) -> (u16, u16) {
+    let (step_x, _) = pixel_size.pixels_per_cell();
+    let pixel_width = pixel_width(scale_x);
+    let rows = lines
+        .iter()
+        .flat_map(|line| match wrap_width {
+            Some(width) => wrap_line(&line.glyphs, width.saturating_mul(step_x), pixel_width)
+                .into_iter()
+                .map(|range| &line.glyphs[range])
+                .collect(),
+            None => vec![line.glyphs.as_slice()],
+        })
+        .collect::<Vec<_>>();
+    let width = rows
+        .iter()
+        .map(|glyphs| line_width(glyphs, pixel_size, scale_x))
+        .max()
+        .unwrap_or(0);
+    let height = text_height(font, pixel_size, scale_y, line_spacing, rows.len());
+    (width, height)
+}
+
+/// Split the glyphs of a line into the ranges of glyphs that fit on each wrapped line, where the
+/// width is measured in pixels of the pixel size.
+///
+/// Lines are broken at the last whitespace that fits, which is left out of both lines, or before
+/// the first grapheme that doesn't fit if the line has no whitespace to break at. Whitespace at
+/// the end of a line may reach past the width, and a single glyph that is wider than the width is
+/// put on a line by itself.
+fn wrap_line(
+    glyphs: &[LayoutGlyph],
+    max_width: u16,
+    pixel_width: impl Fn(&LayoutGlyph) -> u16,
+) -> Vec<Range<usize>> {
+    let is_blank = |index: usize| glyphs[index].grapheme.symbol.trim().is_empty();
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut width = 0u16;
+    // the last whitespace on the current line that comes after a word
+    let mut space = None;
+    let mut has_word = false;
+    for (index, glyph) in glyphs.iter().enumerate() {
+        let glyph_width = pixel_width(glyph);
+        if index > start && !is_blank(index) && width.saturating_add(glyph_width) > max_width {
+            let end = space.unwrap_or(index);
+            let line_end = (start..end)
+                .rposition(|i| !is_blank(i))
+                .map_or(start, |i| start + i + 1);
+            ranges.push(start..line_end);
+            start = (end..index).find(|&i| !is_blank(i)).unwrap_or(index);
+            width = line_pixels(&glyphs[start..index], &pixel_width);
+            space = None;
+            has_word = start < index;
+        }
+        if is_blank(index) {
+            if has_word {
+                space = Some(index);
+            }
+        } else {
+            has_word = true;
+        }
+        width = width.saturating_add(glyph_width);
+    }
+    ranges.push(start..glyphs.len());
+    ranges
+}
+
+/// Wrap each line that is wider than the given number of pixels onto extra lines, which have the
+/// alignment of the line. All but the last of the wrapped lines are justified if `justify` is set.
+fn wrap_lines<'a>(
+    lines: Vec<LayoutLine<'a>>,
+    max_width: u16,
+    pixel_width: impl Fn(&LayoutGlyph) -> u16 + Copy,
+    justify: bool,
+) -> Vec<LayoutLine<'a>> {
+    lines
+        .into_iter()
+        .flat_map(|line| {
+            let ranges = wrap_line(&line.glyphs, max_width, pixel_width);
+            let count = ranges.len();
+            let mut glyphs = line.glyphs.into_iter().map(Some).collect::<Vec<_>>();
+            ranges
+                .into_iter()
+                .enumerate()
+                .map(|(index, range)| LayoutLine {
+                    glyphs: glyphs[range].iter_mut().filter_map(Option::take).collect(),
+                    alignment: line.alignment,
+                    justify: line.justify || (justify && index + 1 < count),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// The number of extra cells after each glyph of a justified line, which spreads the given number
+/// of cells between the words of the line, or between all of its glyphs if it has a single word.
+///
+/// Returns `None` if there is nothing to spread or nowhere to spread it, in which case the line is
+/// aligned as usual.
+fn justify_spacing(glyphs: &[LayoutGlyph], extra: u16) -> Option<Vec<u16>> {
+    let is_blank = |glyph: &LayoutGlyph| glyph.grapheme.symbol.trim().is_empty();
+    let first = glyphs.iter().position(|glyph| !is_blank(glyph))?;
+    let last = glyphs.iter().rposition(|glyph| !is_blank(glyph))?;
+    let mut gaps = (first..last)
+        .filter(|&index| is_blank(&glyphs[index]))
+        .collect::<Vec<_>>();
+    if gaps.is_empty() {
+        gaps = (first..last).collect();
+    }
+    if extra == 0 || gaps.is_empty() {
+        return None;
+    }
+    let count = gaps.len() as u16;
+    let mut spacing = vec![0; glyphs.len()];
+    for (gap, index) in (0..).zip(gaps) {
+        spacing[index] = extra / count + u16::from(gap < extra % count);
+    }
+    Some(spacing)
+}
+
+/// Replace the glyphs at the end of each line that is wider than the given number of pixels with
+/// the ellipsis glyphs, which take the style of the first glyph they replace.
+fn ellipsize_lines<'a>(
+    mut lines: Vec<LayoutLine<'a>>,
+    max_width: u16,
+    pixel_width: impl Fn(&LayoutGlyph) -> u16,
+    ellipsis: &[LayoutGlyph<'a>],
+) -> Vec<LayoutLine<'a>> {
+    let ellipsis_width = line_pixels(ellipsis, &pixel_width);
+    for line in &mut lines {
+        if line_pixels(&line.glyphs, &pixel_width) <= max_width {
+            continue;
+        }
+        let mut width = ellipsis_width;
+        let mut kept = line
+            .glyphs
+            .iter()
+            .take_while(|glyph| {
+                width = width.saturating_add(pixel_width(glyph));
+                width <= max_width
+            })
+            .count();
+        while kept > 0 && line.glyphs[kept - 1].grapheme.symbol.trim().is_empty() {
+            kept -= 1;
+        }
+        let style = line.glyphs[kept].grapheme.style;
+        line.glyphs.truncate(kept);
+        line.glyphs
+            .extend(ellipsis.iter().cloned().map(|mut glyph| {
+                glyph.grapheme.style = style;
+                glyph
+            }));
+        line.justify = false;
+    }
+    lines
+}
+
+/// Remove the lines after the given number of lines and the glyphs that don't fully fit within the
+/// given number of pixels on each line.
+fn hide_overflow<'a>(
+    lines: Vec<LayoutLine<'a>>,
+    max_width: u16,
+    pixel_width: impl Fn(&LayoutGlyph) -> u16,
+    max_lines: usize,
+) -> Vec<LayoutLine<'a>> {
+    lines
+        .into_iter()
+        .take(max_lines)
+        .map(|mut line| {
+            let mut width = 0u16;
+            let visible = line
+                .glyphs
+                .iter()
+                .take_while(|glyph| {
+                    width = width.saturating_add(pixel_width(glyph));
+                    width <= max_width
+                })
+                .count();
+            line.glyphs.truncate(visible);
+            line
+        })
+        .collect()
+}
+
+fn get_alignment_offset(area_width: u16, big_line_width: u16, alignment: Alignment) -> u16 {
     match alignment {
         Alignment::Center => (area_width / 2).saturating_sub(big_line_width / 2),
         Alignment::Right => area_width.saturating_sub(big_line_width),
@@ -137,38 +984,145 @@ fn get_alignment_offset<'a>(
     }
 }
 
-/// Render a single grapheme into a cell by looking up the corresponding 8x8 bitmap in the
-/// `BITMAPS` array and setting the corresponding cells in the buffer.
-fn render_symbol(grapheme: StyledGrapheme, area: Rect, buf: &mut Buffer, pixel_size: &PixelSize) {
-    buf.set_style(area, grapheme.style);
-    let c = grapheme.symbol.chars().next().unwrap(); // TODO: handle multi-char graphemes
-    if let Some(glyph) = font8x8::BASIC_FONTS.get(c) {
-        render_glyph(glyph, area, buf, pixel_size);
+/// Render the lines as FIGlet art, where each character of the art fills a single cell and has
+/// the style of the grapheme it belongs to.
+fn render_figlet(
+    lines: &[Line],
+    style: Style,
+    alignment: Alignment,
+    vertical_alignment: VerticalAlignment,
+    font: &FigletFont,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let text_height = (lines.len() as u16).saturating_mul(font.height());
+    let top = area.top() + vertical_alignment.offset(area.height, text_height);
+    let line_tops = (top..area.bottom()).step_by(font.height().max(1) as usize);
+    for (line, top) in lines.iter().zip(line_tops) {
+        let graphemes = line.styled_graphemes(style).collect::<Vec<_>>();
+        let rows = font.layout_line(graphemes.iter().map(|grapheme| grapheme.symbol));
+        let big_line_width = rows.first().map_or(0, Vec::len) as u16;
+        let alignment = line.alignment.unwrap_or(alignment);
+        let offset = get_alignment_offset(area.width, big_line_width, alignment);
+        let line_area = Rect::new(
+            area.left().saturating_add(offset),
+            top,
+            big_line_width,
+            font.height(),
+        );
+        buf.set_style(line_area.intersection(area), style.patch(line.style));
+        for (row, y) in rows.iter().zip(top..area.bottom()) {
+            for (&(c, index), x) in row.iter().zip(area.left() + offset..area.right()) {
+                buf.get_mut(x, y)
+                    .set_char(c)
+                    .set_style(graphemes[index].style);
+            }
+        }
     }
 }
 
-/// Render a single 8x8 glyph into a cell by setting the corresponding cells in the buffer.
-fn render_glyph(glyph: [u8; 8], area: Rect, buf: &mut Buffer, pixel_size: &PixelSize) {
+/// Render the glyphs of the lines into their cells.
+///
+/// The glyphs are first drawn into a bitmap of the whole area at their pixel positions, so that
+/// glyphs and lines that share a cell, or a glyph that reaches past the end of its cell (for
+/// example because of kerning), are merged instead of cut off. Each font pixel is drawn as a block
+/// of `scale` pixels in the bitmap, and each cell has the style of the grapheme it belongs to.
+fn render_lines(
+    lines: &[LayoutLine],
+    layout: impl IntoIterator<Item = impl IntoIterator<Item = GlyphCell>>,
+    area: Rect,
+    buf: &mut Buffer,
+    pixel_size: &PixelSize,
+    (scale_x, scale_y): (u16, u16),
+) {
     let (step_x, step_y) = pixel_size.pixels_per_cell();
+    let mut canvas = Glyph::new(
+        area.width.saturating_mul(step_x),
+        area.height.saturating_mul(step_y),
+    );
+    // the cells of each line, including the cells that glyphs reach into past the end of the line
+    let mut regions = Vec::new();
+    let mut small = Vec::new();
+    for (line, cells) in lines.iter().zip(layout) {
+        let glyphs = line.glyphs.iter().zip(cells).collect::<Vec<_>>();
+        let Some(&(_, first)) = glyphs.first() else {
+            continue;
+        };
+        let right = glyphs
+            .iter()
+            .map(|(glyph, cell)| {
+                let width = glyph.glyph.as_ref().map_or(0, |bitmap| bitmap.width());
+                let drawn = (glyph.left + width.saturating_sub(glyph.start))
+                    .saturating_mul(scale_x)
+                    .saturating_add(cell.x)
+                    .div_ceil(step_x);
+                cell.area.right().max(area.left().saturating_add(drawn))
+            })
+            .max()
+            .map_or(first.area.right(), |right| right.min(area.right()));
+        let region = Rect {
+            width: right - first.area.x,
+            ..first.area
+        };
+        let bottom = (region.bottom() - area.top()).saturating_mul(step_y);
+        regions.push(region);
+
+        for (glyph, cell) in &glyphs {
+            buf.set_style(cell.area, glyph.grapheme.style);
+            if glyph.small {
+                small.push((glyph.grapheme.symbol, cell.area));
+            }
+            let Some(bitmap) = &glyph.glyph else {
+                continue;
+            };
+            let offset = cell.x.saturating_add(glyph.left.saturating_mul(scale_x));
+            for y in 0..bitmap.height() {
+                let Ok(row) = u16::try_from(i32::from(y) + glyph.top) else {
+                    continue;
+                };
+                let top = cell.y.saturating_add(row.saturating_mul(scale_y));
+                for x in glyph.start..bitmap.width() {
+                    if !bitmap.pixel(x, y) {
+                        continue;
+                    }
+                    let left = offset.saturating_add((x - glyph.start).saturating_mul(scale_x));
+                    for dy in 0..scale_y {
+                        let y = top.saturating_add(dy);
+                        if y >= bottom {
+                            break;
+                        }
+                        for dx in 0..scale_x {
+                            canvas.set_pixel(left.saturating_add(dx), y, true);
+                        }
+                    }
+                }
+            }
+        }
+    }
 
-    let glyph_vertical_index = (0..glyph.len()).step_by(step_y as usize);
-    let glyph_horizontal_bit_selector = (0..8).step_by(step_x as usize);
-
-    for (row, y) in glyph_vertical_index.zip(area.top()..area.bottom()) {
-        for (col, x) in glyph_horizontal_bit_selector
-            .clone()
-            .zip(area.left()..area.right())
-        {
-            let cell = buf.get_mut(x, y);
-            let symbol_character = pixel_size.symbol_for_position(&glyph, row, col);
-            cell.set_char(symbol_character);
+    for region in regions {
+        for y in region.top()..region.bottom() {
+            for x in region.left()..region.right() {
+                let row = (y - area.top()) * step_y;
+                let col = (x - area.left()) * step_x;
+                let symbol_character = pixel_size.symbol_for_position(&canvas, row, col);
+                buf.get_mut(x, y).set_char(symbol_character);
+            }
         }
     }
+    for (symbol, cell) in small {
+        let x = cell.x + cell.width.saturating_sub(1) / 2;
+        let y = cell.y + cell.height.saturating_sub(1) / 2;
+        buf.get_mut(x, y).set_symbol(symbol);
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use super::*;
+    use crate::{Font8x8, Font8x8Table, FontMetrics};
 
     type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
@@ -189,11 +1143,57 @@ mod tests {
                 style,
                 pixel_size,
                 alignment,
+                vertical_alignment: VerticalAlignment::Top,
+                justify: false,
+                wrap: false,
+                overflow: Overflow::Clip,
+                scale_x: 1,
+                scale_y: 1,
+                auto_fit: false,
+                auto_fit_pixel_sizes: AUTO_FIT_PIXEL_SIZES.to_vec(),
+                font: FontRef::default(),
+                proportional: false,
+                proportional_spacing: 1,
+                letter_spacing: 0,
+                line_spacing: 0,
+                fallback_fonts: Vec::new(),
+                missing_glyph: MissingGlyph::Blank,
+                figlet_font: None,
             }
         );
         Ok(())
     }
 
+    #[test]
+    fn size() -> Result<()> {
+        let big_text = BigText::builder()
+            .lines(vec![Line::from("Hello"), Line::from("World!")])
+            .build()?;
+        assert_eq!(big_text.size(), Size::new(48, 16));
+        assert_eq!((big_text.width(), big_text.height()), (48, 16));
+        assert_eq!(big_text.width_constraint(), Constraint::Length(48));
+        assert_eq!(big_text.height_constraint(), Constraint::Length(16));
+
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Sextant)
+            .scale(2)
+            .lines(vec![Line::from("Hello")])
+            .build()?;
+        assert_eq!(big_text.size(), Size::new(40, 6));
+
+        // "il l" is three 4 pixel wide glyphs with 1 pixel of spacing each, and a 4 pixel (half cell)
+        // space
+        let big_text = BigText::builder()
+            .proportional(true)
+            .lines(vec![Line::from("il l")])
+            .build()?;
+        assert_eq!(big_text.size(), Size::new(19, 8));
+
+        let big_text = BigText::builder().lines(vec![]).build()?;
+        assert_eq!(big_text.size(), Size::new(0, 0));
+        Ok(())
+    }
+
     #[test]
     fn render_single_line() -> Result<()> {
         let big_text = BigText::builder()
@@ -896,63 +1896,1574 @@ mod tests {
     }
 
     #[test]
-    fn render_alignment_left() -> Result<()> {
+    fn render_braille_size_single_line() -> Result<()> {
         let big_text = BigText::builder()
-            .pixel_size(PixelSize::Quadrant)
-            .lines(vec![Line::from("Left")])
-            .alignment(Alignment::Left)
+            .pixel_size(PixelSize::Braille)
+            .lines(vec![Line::from("SingleLine")])
             .build()?;
-        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 4));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 2));
         big_text.render(buf.area, &mut buf);
         let expected = Buffer::with_lines(vec![
-            "▜▛      ▗▛▙  ▟                          ",
-            "▐▌  ▟▀▙ ▟▙  ▝█▀                         ",
-            "▐▌▗▌█▀▀ ▐▌   █▗                         ",
-            "▀▀▀▘▝▀▘ ▀▀   ▝▘                         ",
+            "⢾⣍⠓ ⠠⣭  ⣤⠤⣄ ⣠⠤⣠⠄⠈⣿  ⣠⠤⣄ ⢹⡏  ⠠⣭  ⣤⠤⣄ ⣠⠤⣄ ",
+            "⠲⠬⠟ ⠠⠿⠄ ⠿ ⠿ ⣙⣒⡿ ⠠⠿⠄ ⠻⠭⠍ ⠼⠧⠴⠇⠠⠿⠄ ⠿ ⠿ ⠻⠭⠍ ",
         ]);
         assert_eq!(buf, expected);
         Ok(())
     }
 
     #[test]
-    fn render_alignment_right() -> Result<()> {
+    fn render_braille_size_truncated() -> Result<()> {
         let big_text = BigText::builder()
-            .pixel_size(PixelSize::Quadrant)
-            .lines(vec![Line::from("Right")])
-            .alignment(Alignment::Right)
+            .pixel_size(PixelSize::Braille)
+            .lines(vec![Line::from("Truncated")])
             .build()?;
-        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 4));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 35, 1));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec!["⠋⣿⠙ ⢤⣠⢤⡀⣤ ⣤ ⣤⠤⣄ ⣠⠤⣄ ⠠⠤⣄ ⠠⣾⠤ ⣠⠤⣄ ⢀⣈⣿"]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_braille_size_multiple_lines() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Braille)
+            .lines(vec![Line::from("Multi"), Line::from("Lines")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
         big_text.render(buf.area, &mut buf);
         let expected = Buffer::with_lines(vec![
-            "                    ▜▛▜▖ ▀      ▜▌   ▟  ",
-            "                    ▐▙▟▘▝█  ▟▀▟▘▐▙▜▖▝█▀ ",
-            "                    ▐▌▜▖ █  ▜▄█ ▐▌▐▌ █▗ ",
-            "                    ▀▘▝▘▝▀▘ ▄▄▛ ▀▘▝▘ ▝▘ ",
+            "⣿⣦⣾⡇⣤ ⣤ ⠈⣿  ⠠⣾⠤ ⠠⣭  ",
+            "⠿⠈⠸⠇⠻⠤⠻⠄⠠⠿⠄  ⠻⠔ ⠠⠿⠄ ",
+            "⢹⡏  ⠠⣭  ⣤⠤⣄ ⣠⠤⣄ ⣠⠤⠤ ",
+            "⠼⠧⠴⠇⠠⠿⠄ ⠿ ⠿ ⠻⠭⠍ ⠬⠭⠗ ",
         ]);
         assert_eq!(buf, expected);
         Ok(())
     }
 
     #[test]
-    fn render_alignment_center() -> Result<()> {
+    fn render_braille_size_line_style() -> Result<()> {
         let big_text = BigText::builder()
-            .pixel_size(PixelSize::Quadrant)
-            .lines(vec![Line::from("Centered"), Line::from("Lines")])
-            .alignment(Alignment::Center)
+            .pixel_size(PixelSize::Braille)
+            .lines(vec![
+                Line::from("Red".red()),
+                Line::from("Green".green()),
+                Line::from("Blue".blue()),
+            ])
             .build()?;
-        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 8));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 6));
+        big_text.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![
+            "⢹⣏⣹⠆⣠⠤⣄ ⢀⣈⣿         ",
+            "⠼⠇⠹⠆⠻⠭⠍ ⠻⠤⠻⠄        ",
+            "⣴⠋⠙⠂⢤⣠⢤⡀⣠⠤⣄ ⣠⠤⣄ ⣤⠤⣄ ",
+            "⠙⠦⠽⠇⠼⠧⠈⠁⠻⠭⠍ ⠻⠭⠍ ⠿ ⠿ ",
+            "⢹⣏⣹⠆⠈⣿  ⣤ ⣤ ⣠⠤⣄     ",
+            "⠼⠧⠼⠃⠠⠿⠄ ⠻⠤⠻⠄⠻⠭⠍     ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 12, 2), Style::new().red());
+        expected.set_style(Rect::new(0, 2, 20, 2), Style::new().green());
+        expected.set_style(Rect::new(0, 4, 16, 2), Style::new().blue());
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_octant_size_single_line() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Octant)
+            .lines(vec![Line::from("SingleLine")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 2));
         big_text.render(buf.area, &mut buf);
         let expected = Buffer::with_lines(vec![
-            "    ▗▛▜▖         ▟               ▝█     ",
-            "    █   ▟▀▙ █▀▙ ▝█▀ ▟▀▙ ▜▟▜▖▟▀▙ ▗▄█     ",
-            "    ▜▖▗▖█▀▀ █ █  █▗ █▀▀ ▐▌▝▘█▀▀ █ █     ",
-            "     ▀▀ ▝▀▘ ▀ ▀  ▝▘ ▝▀▘ ▀▀  ▝▀▘ ▝▀▝▘    ",
-            "          ▜▛   ▀                        ",
-            "          ▐▌  ▝█  █▀▙ ▟▀▙ ▟▀▀           ",
-            "          ▐▌▗▌ █  █ █ █▀▀ ▝▀▙           ",
-            "          ▀▀▀▘▝▀▘ ▀ ▀ ▝▀▘ ▀▀▘           ",
+            "𜶪𜶾𜴇 𜴘𜷝  ▄𜴧𜶻 𜷋𜴧𜷋𜴉𜺫█  𜷋𜴧𜶻 𜶘𜵊  𜴘𜷝  ▄𜴧𜶻 𜷋𜴧𜶻 ",
+            "𜴣𜴩𜴗 𜴘🮅𜴉 🮅 🮅 𜶶𜶷𜵰 𜴘🮅𜴉 𜴦𜴪𜴌 𜴱𜴬𜴯𜴍𜴘🮅𜴉 🮅 🮅 𜴦𜴪𜴌 ",
         ]);
         assert_eq!(buf, expected);
         Ok(())
     }
+
+    #[test]
+    fn render_octant_size_multiple_lines() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Octant)
+            .lines(vec![Line::from("Multi"), Line::from("Lines")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "█𜷞𜷥▌▄ ▄ 𜺫█  𜴘𜷥𜴧 𜴘𜷝  ",
+            "🮅𜺫𜴡𜴍𜴦𜴧𜴦𜴉𜴘🮅𜴉  𜴦𜴐 𜴘🮅𜴉 ",
+            "𜶘𜵊  𜴘𜷝  ▄𜴧𜶻 𜷋𜴧𜶻 𜷋𜴧𜴧 ",
+            "𜴱𜴬𜴯𜴍𜴘🮅𜴉 🮅 🮅 𜴦𜴪𜴌 𜴩𜴪𜴕 ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    /// A font that renders every grapheme except a space as a hollow 4x4 box.
+    #[derive(Debug)]
+    struct BoxFont;
+
+    impl Font for BoxFont {
+        fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>> {
+            if grapheme == " " {
+                return None;
+            }
+            let mut glyph = Glyph::filled(4, 4);
+            glyph.set_pixel(1, 1, false);
+            glyph.set_pixel(2, 1, false);
+            glyph.set_pixel(1, 2, false);
+            glyph.set_pixel(2, 2, false);
+            Some(Cow::Owned(glyph))
+        }
+
+        fn metrics(&self) -> FontMetrics {
+            FontMetrics::new(5, 4, 4)
+        }
+    }
+
+    /// A font that renders the same glyphs as `BoxFont`, but moves "a" and "b" closer together
+    /// and every other pair further apart.
+    #[derive(Debug)]
+    struct KernedBoxFont;
+
+    impl Font for KernedBoxFont {
+        fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>> {
+            BoxFont.glyph(grapheme)
+        }
+
+        fn metrics(&self) -> FontMetrics {
+            BoxFont.metrics()
+        }
+
+        fn kerning(&self, left: &str, right: &str) -> i16 {
+            if (left, right) == ("a", "b") {
+                -2
+            } else {
+                1
+            }
+        }
+    }
+
+    #[test]
+    fn render_kerning() -> Result<()> {
+        let big_text = BigText::builder()
+            .font(&KernedBoxFont)
+            .lines(vec![Line::from("abc")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "███████  ████   ",
+            "█  █  █  █  █   ",
+            "█  █  █  █  █   ",
+            "███████  ████   ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_auto_kerning() -> Result<()> {
+        let font = Font8x8::ALL.with_auto_kerning(true);
+        let big_text = BigText::builder()
+            .font(&font)
+            .lines(vec![Line::from("LTo")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "████   ██████           ",
+            " ██    █ ██ █           ",
+            " ██      ██   ████      ",
+            " ██      ██  ██  ██     ",
+            " ██   █  ██  ██  ██     ",
+            " ██  ██  ██  ██  ██     ",
+            "███████ ████  ████      ",
+            "                        ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_combining_marks() -> Result<()> {
+        let big_text = BigText::builder()
+            .lines(vec![Line::from(vec![
+                Span::raw(""),
+                Span::raw("e\u{301}q\u{308}"),
+                Span::raw(""),
+            ])])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "   ███  ██  ██  ",
+            "                ",
+            " ████    ███ ██ ",
+            "██  ██  ██  ██  ",
+            "██████  ██  ██  ",
+            "██       █████  ",
+            " ████       ██  ",
+            "           ████ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_missing_glyph_blank() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .missing_glyph(MissingGlyph::Blank)
+            .lines(vec![Line::from("a€b")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "        ▜▌  ",
+            "▝▀▙     ▐▙▄ ",
+            "▟▀█     ▐▌▐▌",
+            "▝▀▝▘    ▀▝▀ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_missing_glyph_tofu() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .missing_glyph(MissingGlyph::Tofu)
+            .lines(vec![Line::from("a€b")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "    ▛▀▀▌▜▌  ",
+            "▝▀▙ ▌  ▌▐▙▄ ",
+            "▟▀█ ▌  ▌▐▌▐▌",
+            "▝▀▝▘▀▀▀▘▀▝▀ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_missing_glyph_char() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .missing_glyph(MissingGlyph::Char('?'))
+            .lines(vec![Line::from("a€b")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "    ▟▀▙ ▜▌  ",
+            "▝▀▙  ▗▛ ▐▙▄ ",
+            "▟▀█  ▀  ▐▌▐▌",
+            "▝▀▝▘ ▀  ▀▝▀ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_missing_glyph_glyph() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .missing_glyph(MissingGlyph::Glyph(Glyph::filled(2, 8)))
+            .lines(vec![Line::from("a€b")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "    █   ▜▌  ",
+            "▝▀▙ █   ▐▙▄ ",
+            "▟▀█ █   ▐▌▐▌",
+            "▝▀▝▘█   ▀▝▀ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_missing_glyph_small() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .missing_glyph(MissingGlyph::Small)
+            .lines(vec![Line::from("a€b")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "        ▜▌  ",
+            "▝▀▙  €  ▐▙▄ ",
+            "▟▀█     ▐▌▐▌",
+            "▝▀▝▘    ▀▝▀ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_missing_glyph_skip() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .missing_glyph(MissingGlyph::Skip)
+            .lines(vec![Line::from("a€b")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "    ▜▌      ",
+            "▝▀▙ ▐▙▄     ",
+            "▟▀█ ▐▌▐▌    ",
+            "▝▀▝▘▀▝▀     ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_missing_glyph_whitespace() -> Result<()> {
+        let big_text = BigText::builder()
+            .font(&BoxFont)
+            .missing_glyph(MissingGlyph::Tofu)
+            .lines(vec![Line::from("a b")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "████      ████ ",
+            "█  █      █  █ ",
+            "█  █      █  █ ",
+            "████      ████ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn build_fallback_fonts() -> Result<()> {
+        let greek = Font8x8::EMPTY.with_table(Font8x8Table::Greek);
+        let big_text = BigText::builder()
+            .lines(vec![])
+            .fallback_font(&greek)
+            .fallback_font(&BoxFont)
+            .build()?;
+        assert_eq!(
+            big_text.fallback_fonts,
+            [FontRef(&greek), FontRef(&BoxFont)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn render_fallback_fonts() -> Result<()> {
+        let font = Font8x8::BASIC;
+        let greek = Font8x8::EMPTY.with_table(Font8x8Table::Greek);
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .font(&font)
+            .fallback_font(&greek)
+            .fallback_font(&BoxFont)
+            .lines(vec![Line::from("aλ€")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "    ▄      ",
+            "▝▀▙ ▝▙  ▄▄ ",
+            "▟▀█ ▗▛▙ ▌▐ ",
+            "▝▀▝▘▀ ▝▘▀▀ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_fallback_font_baseline() -> Result<()> {
+        let font = Font8x8::BASIC;
+        let small = PatternFont::new(&["###", "#.#", "###"]);
+        let big_text = BigText::builder()
+            .font(&font)
+            .fallback_font(&small)
+            .lines(vec![Line::from("a€a")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 19, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "                   ",
+            "                   ",
+            " ████       ████   ",
+            "    ██         ██  ",
+            " █████  ███ █████  ",
+            "██  ██  █ ███  ██  ",
+            " ███ ██ ███ ███ ██ ",
+            "                   ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    /// A font like `BoxFont` with a 5 pixel wide cell, but with 9 pixel wide boxes for wide
+    /// graphemes.
+    #[derive(Debug)]
+    struct WideBoxFont;
+
+    impl Font for WideBoxFont {
+        fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>> {
+            let mut glyph = BoxFont.glyph(grapheme)?.into_owned();
+            if grapheme.width() > 1 {
+                glyph = Glyph::filled(9, 4);
+                for x in 1..8 {
+                    glyph.set_pixel(x, 1, false);
+                    glyph.set_pixel(x, 2, false);
+                }
+            }
+            Some(Cow::Owned(glyph))
+        }
+
+        fn metrics(&self) -> FontMetrics {
+            BoxFont.metrics()
+        }
+    }
+
+    #[test]
+    fn render_wide_glyphs() -> Result<()> {
+        let big_text = BigText::builder()
+            .font(&WideBoxFont)
+            .alignment(Alignment::Right)
+            .lines(vec![Line::from("a一b"), Line::from("一")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 21, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            " ████ █████████ ████ ",
+            " █  █ █       █ █  █ ",
+            " █  █ █       █ █  █ ",
+            " ████ █████████ ████ ",
+            "           █████████ ",
+            "           █       █ ",
+            "           █       █ ",
+            "           █████████ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_wide_graphemes_centered() -> Result<()> {
+        // the 8 pixel wide glyph of "あ" is drawn twice as wide to fill both of its cells
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .alignment(Alignment::Center)
+            .lines(vec![Line::from("aあb"), Line::from("ab")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "    ▄▄█▄▄▄  ▜▌  ",
+            "▝▀▙   █▄▄▄  ▐▙▄ ",
+            "▟▀█ ▄▀█▄▀ █ ▐▌▐▌",
+            "▝▀▝▘ ▀▀  ▀  ▀▝▀ ",
+            "        ▜▌      ",
+            "    ▝▀▙ ▐▙▄     ",
+            "    ▟▀█ ▐▌▐▌    ",
+            "    ▝▀▝▘▀▝▀     ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_custom_font() -> Result<()> {
+        let big_text = BigText::builder()
+            .font(&BoxFont)
+            .lines(vec![Line::from("a b"), Line::from("cd")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "████      ████  ",
+            "█  █      █  █  ",
+            "█  █      █  █  ",
+            "████      ████  ",
+            "████ ████       ",
+            "█  █ █  █       ",
+            "█  █ █  █       ",
+            "████ ████       ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    /// A font that renders every grapheme except a space with the same glyph, which is given as
+    /// rows of `#` (set) and `.` (unset) pixels.
+    #[derive(Debug)]
+    struct PatternFont(Glyph);
+
+    impl PatternFont {
+        fn new(rows: &[&str]) -> Self {
+            let width = rows.first().map_or(0, |row| row.len()) as u16;
+            let mut glyph = Glyph::new(width, rows.len() as u16);
+            for (y, row) in (0..).zip(rows) {
+                for (x, pixel) in (0..).zip(row.chars()) {
+                    glyph.set_pixel(x, y, pixel == '#');
+                }
+            }
+            Self(glyph)
+        }
+    }
+
+    impl Font for PatternFont {
+        fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>> {
+            (grapheme != " ").then_some(Cow::Borrowed(&self.0))
+        }
+
+        fn metrics(&self) -> FontMetrics {
+            FontMetrics::new(self.0.width(), self.0.height(), self.0.height())
+        }
+    }
+
+    const PIXEL_SIZES: [PixelSize; 8] = [
+        PixelSize::Full,
+        PixelSize::HalfHeight,
+        PixelSize::HalfWidth,
+        PixelSize::Quadrant,
+        PixelSize::ThirdHeight,
+        PixelSize::Sextant,
+        PixelSize::Braille,
+        PixelSize::Octant,
+    ];
+
+    /// Lays out the lines of the widget in the given area.
+    fn layout_cells(big_text: &BigText, area: Rect) -> Vec<Vec<Rect>> {
+        let lines = big_text.layout_lines();
+        layout(
+            area,
+            big_text.font.0,
+            &big_text.pixel_size,
+            big_text.scale(),
+            big_text.line_spacing,
+            big_text.vertical_alignment,
+            &lines,
+        )
+        .into_iter()
+        .map(|line| line.into_iter().map(|cell| cell.area).collect())
+        .collect()
+    }
+
+    #[test]
+    fn layout_arbitrary_glyph_sizes() -> Result<()> {
+        let area = Rect::new(0, 0, 100, 100);
+        for (width, height) in [(5, 7), (6, 12), (16, 16), (3, 20)] {
+            let font = PatternFont(Glyph::filled(width, height));
+            for pixel_size in PIXEL_SIZES {
+                let (step_x, step_y) = pixel_size.pixels_per_cell();
+                let cell_width = width.div_ceil(step_x);
+                let cell_height = height.div_ceil(step_y);
+                // the second glyph starts in the cell that the first glyph ends in
+                let second_left = width / step_x;
+                let second_width = (width * 2).div_ceil(step_x) - second_left;
+                let big_text = BigText::builder()
+                    .font(&font)
+                    .pixel_size(pixel_size)
+                    .lines(vec![Line::from("ab"), Line::from("c")])
+                    .build()?;
+                assert_eq!(
+                    layout_cells(&big_text, area),
+                    [
+                        vec![
+                            Rect::new(0, 0, cell_width, cell_height),
+                            Rect::new(second_left, 0, second_width, cell_height),
+                        ],
+                        vec![Rect::new(0, cell_height, cell_width, cell_height)],
+                    ],
+                    "{width}x{height} {pixel_size:?}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn layout_scale() -> Result<()> {
+        let area = Rect::new(0, 0, 200, 200);
+        let font = PatternFont(Glyph::filled(5, 7));
+        for pixel_size in PIXEL_SIZES {
+            let (step_x, step_y) = pixel_size.pixels_per_cell();
+            for (scale_x, scale_y) in [(2u16, 2u16), (3, 3), (2, 5)] {
+                let cell_width = (5 * scale_x).div_ceil(step_x);
+                let cell_height = (7 * scale_y).div_ceil(step_y);
+                let second_left = 5 * scale_x / step_x;
+                let second_width = (10 * scale_x).div_ceil(step_x) - second_left;
+                let big_text = BigText::builder()
+                    .font(&font)
+                    .pixel_size(pixel_size)
+                    .scale_x(scale_x)
+                    .scale_y(scale_y)
+                    .lines(vec![Line::from("ab")])
+                    .build()?;
+                assert_eq!(
+                    layout_cells(&big_text, area),
+                    [vec![
+                        Rect::new(0, 0, cell_width, cell_height),
+                        Rect::new(second_left, 0, second_width, cell_height),
+                    ]],
+                    "{scale_x}x{scale_y} {pixel_size:?}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn build_scale() -> Result<()> {
+        let big_text = BigText::builder()
+            .lines(vec![Line::from("a")])
+            .scale(3)
+            .build()?;
+        assert_eq!(big_text.scale(), (3, 3));
+        let big_text = BigText::builder()
+            .lines(vec![Line::from("a")])
+            .scale(0)
+            .scale_x(2)
+            .build()?;
+        assert_eq!(big_text.scale(), (2, 1));
+        Ok(())
+    }
+
+    /// Wraps the lines of the widget to the given width and returns the graphemes of each line.
+    fn wrapped_lines(big_text: &BigText, width: u16) -> Vec<String> {
+        let (step_x, _) = big_text.pixel_size.pixels_per_cell();
+        let pixel_width = pixel_width(big_text.scale_x);
+        wrap_lines(big_text.layout_lines(), width * step_x, pixel_width, false)
+            .iter()
+            .map(|line| {
+                line.glyphs
+                    .iter()
+                    .map(|glyph| glyph.grapheme.symbol)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wrap() -> Result<()> {
+        let big_text = BigText::builder()
+            .lines(vec![Line::from("Hello big world"), Line::from("abcdefgh")])
+            .build()?;
+        assert_eq!(
+            wrapped_lines(&big_text, 48),
+            ["Hello", "big", "world", "abcdef", "gh"]
+        );
+        assert_eq!(
+            wrapped_lines(&big_text, 24),
+            ["Hel", "lo", "big", "wor", "ld", "abc", "def", "gh"]
+        );
+        // a glyph that is wider than the area is put on a line by itself
+        assert_eq!(
+            wrapped_lines(&big_text, 4),
+            [
+                "H", "e", "l", "l", "o", "b", "i", "g", "w", "o", "r", "l", "d", "a", "b", "c",
+                "d", "e", "f", "g", "h"
+            ]
+        );
+        // lines that fit are left alone, including leading and trailing whitespace
+        let big_text = BigText::builder()
+            .lines(vec![Line::from(" ab  cd "), Line::from("ab   ")])
+            .build()?;
+        assert_eq!(wrapped_lines(&big_text, 64), [" ab  cd ", "ab   "]);
+        assert_eq!(wrapped_lines(&big_text, 40), [" ab", "cd ", "ab   "]);
+        Ok(())
+    }
+
+    #[test]
+    fn auto_fit_wrap() -> Result<()> {
+        let builder = || {
+            let mut builder = BigText::builder();
+            builder.auto_fit(true).lines(vec![Line::from("ab cd")]);
+            builder
+        };
+        let area = Rect::new(0, 0, 16, 8);
+        let big_text = builder().build()?;
+        assert_eq!(auto_fit_size(&big_text, area), (PixelSize::Braille, (1, 1)));
+        let big_text = builder().wrap(true).build()?;
+        assert_eq!(
+            auto_fit_size(&big_text, area),
+            (PixelSize::HalfHeight, (1, 1))
+        );
+        Ok(())
+    }
+
+    /// Chooses the pixel size and scale for the lines of the widget in the given area.
+    fn auto_fit_size(big_text: &BigText, area: Rect) -> (PixelSize, (u16, u16)) {
+        big_text.auto_fit_size(area, &big_text.layout_lines())
+    }
+
+    #[test]
+    fn auto_fit() -> Result<()> {
+        // "Hi" is 16x8 font pixels
+        let big_text = BigText::builder()
+            .auto_fit(true)
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        let fit = |width, height| auto_fit_size(&big_text, Rect::new(0, 0, width, height));
+        assert_eq!(fit(32, 16), (PixelSize::Full, (2, 2)));
+        assert_eq!(fit(40, 20), (PixelSize::Quadrant, (5, 5)));
+        assert_eq!(fit(20, 8), (PixelSize::Full, (1, 1)));
+        assert_eq!(fit(16, 4), (PixelSize::HalfHeight, (1, 1)));
+        assert_eq!(fit(8, 2), (PixelSize::Braille, (1, 1)));
+        // nothing fits, so the smallest pixel size is used
+        assert_eq!(fit(4, 1), (PixelSize::Braille, (1, 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn auto_fit_pixel_sizes() -> Result<()> {
+        let big_text = BigText::builder()
+            .auto_fit(true)
+            .auto_fit_pixel_sizes([PixelSize::Full, PixelSize::Quadrant])
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        let fit = |width, height| auto_fit_size(&big_text, Rect::new(0, 0, width, height));
+        assert_eq!(fit(16, 4), (PixelSize::Quadrant, (1, 1)));
+        assert_eq!(fit(4, 1), (PixelSize::Quadrant, (1, 1)));
+        let big_text = BigText::builder()
+            .auto_fit(true)
+            .auto_fit_pixel_sizes([])
+            .pixel_size(PixelSize::Sextant)
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        assert_eq!(
+            auto_fit_size(&big_text, Rect::new(0, 0, 4, 1)),
+            (PixelSize::Sextant, (1, 1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn layout_empty_glyph_size() -> Result<()> {
+        let font = PatternFont(Glyph::new(0, 0));
+        let big_text = BigText::builder()
+            .font(&font)
+            .lines(vec![Line::from("ab")])
+            .build()?;
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 4, 4)),
+            [vec![Rect::new(0, 0, 1, 1), Rect::new(1, 0, 1, 1)]]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn layout_proportional() -> Result<()> {
+        let big_text = BigText::builder()
+            .proportional(true)
+            .lines(vec![Line::from("il l")])
+            .build()?;
+        // "i" and "l" are 4 pixels wide plus 1 pixel of spacing, and a space is half a cell
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 40, 8)),
+            [vec![
+                Rect::new(0, 0, 5, 8),
+                Rect::new(5, 0, 5, 8),
+                Rect::new(10, 0, 4, 8),
+                Rect::new(14, 0, 5, 8),
+            ]]
+        );
+        let big_text = BigText::builder()
+            .proportional(true)
+            .proportional_spacing(0)
+            .pixel_size(PixelSize::Quadrant)
+            .alignment(Alignment::Right)
+            .lines(vec![Line::from("il l")])
+            .build()?;
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 10, 4)),
+            [vec![
+                Rect::new(2, 0, 2, 4),
+                Rect::new(4, 0, 2, 4),
+                Rect::new(6, 0, 2, 4),
+                Rect::new(8, 0, 2, 4),
+            ]]
+        );
+        Ok(())
+    }
+
+    /// A 5x7 "A", as used by many small LCD fonts.
+    const GLYPH_5X7: [&str; 7] = [
+        ".###.", //
+        "#...#", "#...#", "#####", "#...#", "#...#", "#...#",
+    ];
+
+    #[test]
+    fn render_scale_full() -> Result<()> {
+        let font = PatternFont::new(&["##.", "#.#"]);
+        let big_text = BigText::builder()
+            .font(&font)
+            .scale(2)
+            .lines(vec![Line::from("aa"), Line::from("a")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "████  ████  ",
+            "████  ████  ",
+            "██  ████  ██",
+            "██  ████  ██",
+            "████        ",
+            "████        ",
+            "██  ██      ",
+            "██  ██      ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_scale_quadrant() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .scale(3)
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 12));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "███   ███      ███      ",
+            "███   ███      ▀▀▀      ",
+            "███   ███               ",
+            "███   ███    ▐████      ",
+            "███▄▄▄███    ▝▀███      ",
+            "█████████      ███      ",
+            "███   ███      ███      ",
+            "███   ███      ███      ",
+            "███   ███      ███      ",
+            "███   ███    ▐█████▌    ",
+            "▀▀▀   ▀▀▀    ▝▀▀▀▀▀▘    ",
+            "                        ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_scale_x_half_height() -> Result<()> {
+        let font = PatternFont::new(&["##.", "#.#", ".#."]);
+        let big_text = BigText::builder()
+            .font(&font)
+            .pixel_size(PixelSize::HalfHeight)
+            .scale_x(3)
+            .lines(vec![Line::from("ab")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 18, 2));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec!["███▀▀▀▄▄▄███▀▀▀▄▄▄", "   ▀▀▀      ▀▀▀   "]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_auto_fit() -> Result<()> {
+        let big_text = BigText::builder()
+            .auto_fit(true)
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 18, 5));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "██  ██    ▀▀      ",
+            "██▄▄██   ▀██      ",
+            "██  ██    ██      ",
+            "▀▀  ▀▀   ▀▀▀▀     ",
+            "                  ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_5x7_third_height() -> Result<()> {
+        let font = PatternFont::new(&GLYPH_5X7);
+        let big_text = BigText::builder()
+            .font(&font)
+            .pixel_size(PixelSize::ThirdHeight)
+            .lines(vec![Line::from("aa"), Line::from("a")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "🬹🬂🬂🬂🬹🬹🬂🬂🬂🬹",
+            "█🬂🬂🬂██🬂🬂🬂█",
+            "🬂   🬂🬂   🬂",
+            "🬹🬂🬂🬂🬹     ",
+            "█🬂🬂🬂█     ",
+            "🬂   🬂     ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_5x7_sextant() -> Result<()> {
+        let font = PatternFont::new(&GLYPH_5X7);
+        let big_text = BigText::builder()
+            .font(&font)
+            .pixel_size(PixelSize::Sextant)
+            .lines(vec![Line::from("aa"), Line::from("a")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 6));
+        big_text.render(buf.area, &mut buf);
+        // the glyphs are 5 pixels wide, so the second glyph starts in the middle of a cell
+        let expected = Buffer::with_lines(vec![
+            "🬔🬂🬹🬂🬧 ",
+            "🬕🬂█🬂🬨 ",
+            "🬀 🬂 🬁 ",
+            "🬔🬂🬓   ",
+            "🬕🬂▌   ",
+            "🬀 🬀   ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_6x12_quadrant() -> Result<()> {
+        let font = PatternFont::new(&[
+            "..##..", //
+            ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "......", "......",
+        ]);
+        let big_text = BigText::builder()
+            .font(&font)
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("aa")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "▗▀▖▗▀▖",
+            "▌ ▐▌ ▐",
+            "▙▄▟▙▄▟",
+            "▌ ▐▌ ▐",
+            "▌ ▐▌ ▐",
+            "      ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_16x16_sextant() -> Result<()> {
+        let font = PatternFont::new(&[
+            "################", //
+            "#..............#",
+            "#..............#",
+            "#..............#",
+            "#..............#",
+            "#..............#",
+            "#..............#",
+            "#......##......#",
+            "#......##......#",
+            "#..............#",
+            "#..............#",
+            "#..............#",
+            "#..............#",
+            "#..............#",
+            "#..............#",
+            "################",
+        ]);
+        let big_text = BigText::builder()
+            .font(&font)
+            .pixel_size(PixelSize::Sextant)
+            .lines(vec![Line::from("a")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "🬕🬂🬂🬂🬂🬂🬂🬨",
+            "▌      ▐",
+            "▌  🬦🬓  ▐",
+            "▌      ▐",
+            "▌      ▐",
+            "🬂🬂🬂🬂🬂🬂🬂🬂",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_proportional() -> Result<()> {
+        let big_text = BigText::builder()
+            .proportional(true)
+            .lines(vec![Line::from("Illinois")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 44, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "████ ███  ███   ██                 ██       ",
+            " ██   ██   ██                               ",
+            " ██   ██   ██  ███  █████   ████  ███   ████",
+            " ██   ██   ██   ██  ██  ██ ██  ██  ██  ██   ",
+            " ██   ██   ██   ██  ██  ██ ██  ██  ██   ████",
+            " ██   ██   ██   ██  ██  ██ ██  ██  ██      █",
+            "████ ████ ████ ████ ██  ██  ████  ████ █████",
+            "                                            ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_proportional_quadrant_size_centered() -> Result<()> {
+        let big_text = BigText::builder()
+            .proportional(true)
+            .proportional_spacing(2)
+            .pixel_size(PixelSize::Quadrant)
+            .alignment(Alignment::Center)
+            .lines(vec![Line::from("Illinois")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "  ▜▛ ▜▌ ▜▌ ▝▘         ▝▘      ",
+            "  ▐▌ ▐▌ ▐▌ ▜▌ █▀▙ ▟▀▙ ▜▌ ▟▀▀  ",
+            "  ▐▌ ▐▌ ▐▌ ▐▌ █ █ █ █ ▐▌ ▝▀▙  ",
+            "  ▀▀ ▀▀ ▀▀ ▀▀ ▀ ▀ ▝▀▘ ▀▀ ▀▀▘  ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_alignment_left() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Left")])
+            .alignment(Alignment::Left)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "▜▛      ▗▛▙  ▟                          ",
+            "▐▌  ▟▀▙ ▟▙  ▝█▀                         ",
+            "▐▌▗▌█▀▀ ▐▌   █▗                         ",
+            "▀▀▀▘▝▀▘ ▀▀   ▝▘                         ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_alignment_right() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Right")])
+            .alignment(Alignment::Right)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "                    ▜▛▜▖ ▀      ▜▌   ▟  ",
+            "                    ▐▙▟▘▝█  ▟▀▟▘▐▙▜▖▝█▀ ",
+            "                    ▐▌▜▖ █  ▜▄█ ▐▌▐▌ █▗ ",
+            "                    ▀▘▝▘▝▀▘ ▄▄▛ ▀▘▝▘ ▝▘ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_alignment_center() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Centered"), Line::from("Lines")])
+            .alignment(Alignment::Center)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "    ▗▛▜▖         ▟               ▝█     ",
+            "    █   ▟▀▙ █▀▙ ▝█▀ ▟▀▙ ▜▟▜▖▟▀▙ ▗▄█     ",
+            "    ▜▖▗▖█▀▀ █ █  █▗ █▀▀ ▐▌▝▘█▀▀ █ █     ",
+            "     ▀▀ ▝▀▘ ▀ ▀  ▝▘ ▝▀▘ ▀▀  ▝▀▘ ▝▀▝▘    ",
+            "          ▜▛   ▀                        ",
+            "          ▐▌  ▝█  █▀▙ ▟▀▙ ▟▀▀           ",
+            "          ▐▌▗▌ █  █ █ █▀▀ ▝▀▙           ",
+            "          ▀▀▀▘▝▀▘ ▀ ▀ ▝▀▘ ▀▀▘           ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_vertical_alignment_center() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Top"), Line::from("Mid")])
+            .vertical_alignment(VerticalAlignment::Center)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 11));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "            ",
+            "▛█▜         ",
+            " █  ▟▀▙ ▜▞▜▖",
+            " █  █ █ ▐▙▟▘",
+            "▝▀▘ ▝▀▘ ▟▙  ",
+            "█▖▟▌ ▀   ▝█ ",
+            "███▌▝█  ▗▄█ ",
+            "█▝▐▌ █  █ █ ",
+            "▀ ▝▘▝▀▘ ▝▀▝▘",
+            "            ",
+            "            ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_vertical_alignment_bottom() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Bot")])
+            .alignment(Alignment::Right)
+            .vertical_alignment(VerticalAlignment::Bottom)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "              ",
+            "              ",
+            "  ▜▛▜▖     ▟  ",
+            "  ▐▙▟▘▟▀▙ ▝█▀ ",
+            "  ▐▌▐▌█ █  █▗ ",
+            "  ▀▀▀ ▝▀▘  ▝▘ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_vertical_alignment_truncated() -> Result<()> {
+        // the lines are taller than the area, so they are aligned to the top and cut off
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Top"), Line::from("Cut")])
+            .vertical_alignment(VerticalAlignment::Bottom)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "▛█▜         ",
+            " █  ▟▀▙ ▜▞▜▖",
+            " █  █ █ ▐▙▟▘",
+            "▝▀▘ ▝▀▘ ▟▙  ",
+            "▗▛▜▖     ▟  ",
+            "█   █ █ ▝█▀ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_line_alignment() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![
+                Line::from("Title"),
+                Line::from("Sub").right_aligned(),
+                Line::from("Mid").centered(),
+            ])
+            .alignment(Alignment::Left)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 12));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "▛█▜  ▀   ▟  ▝█          ",
+            " █  ▝█  ▝█▀  █  ▟▀▙     ",
+            " █   █   █▗  █  █▀▀     ",
+            "▝▀▘ ▝▀▘  ▝▘ ▝▀▘ ▝▀▘     ",
+            "            ▟▀▙     ▜▌  ",
+            "            ▜▙  █ █ ▐▙▄ ",
+            "            ▄▝█ █ █ ▐▌▐▌",
+            "            ▝▀▘ ▝▀▝▘▀▝▀ ",
+            "      █▖▟▌ ▀   ▝█       ",
+            "      ███▌▝█  ▗▄█       ",
+            "      █▝▐▌ █  █ █       ",
+            "      ▀ ▝▘▝▀▘ ▝▀▝▘      ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_justify() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![
+                Line::from("A B C"),
+                Line::from("ABC"),
+                Line::from("Last"),
+            ])
+            .alignment(Alignment::Right)
+            .justify(true)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 26, 12));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "▗█▖        ▜▛▜▖       ▗▛▜▖",
+            "█ █        ▐▙▟▘       █   ",
+            "█▀█        ▐▌▐▌       ▜▖▗▖",
+            "▀ ▀        ▀▀▀         ▀▀ ",
+            "▗█▖        ▜▛▜▖       ▗▛▜▖",
+            "█ █        ▐▙▟▘       █   ",
+            "█▀█        ▐▌▐▌       ▜▖▗▖",
+            "▀ ▀        ▀▀▀         ▀▀ ",
+            "          ▜▛           ▟  ",
+            "          ▐▌  ▝▀▙ ▟▀▀ ▝█▀ ",
+            "          ▐▌▗▌▟▀█ ▝▀▙  █▗ ",
+            "          ▀▀▀▘▝▀▝▘▀▀▘  ▝▘ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn layout_justify() -> Result<()> {
+        let big_text = BigText::builder()
+            .lines(vec![
+                Line::from(" a  b "),
+                Line::from("ab"),
+                Line::from("a"),
+                Line::from("abcd"),
+            ])
+            .justify(true)
+            .build()?;
+        let area = Rect::new(0, 0, 51, 32);
+        let lines = layout_cells(&big_text, area);
+        let columns = |line: &[Rect]| line.iter().map(|cell| cell.x).collect::<Vec<_>>();
+        // the 3 extra cells are spread over the two spaces between the words
+        assert_eq!(columns(&lines[0]), [0, 8, 16, 26, 35, 43]);
+        // the 35 extra cells are spread between the glyphs
+        assert_eq!(columns(&lines[1]), [0, 43]);
+        // a single glyph can't be justified
+        assert_eq!(columns(&lines[2]), [0]);
+        // the last line is not justified
+        assert_eq!(columns(&lines[3]), [0, 8, 16, 24]);
+        Ok(())
+    }
+
+    #[test]
+    fn render_wrap() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Wrap me now")])
+            .alignment(Alignment::Center)
+            .wrap(true)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 28, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "█ ▐▌                        ",
+            "█▗▐▌▜▟▜▖▝▀▙ ▜▞▜▖    █▄█▖▟▀▙ ",
+            "█▛█▌▐▌▝▘▟▀█ ▐▙▟▘    █▜▜▌█▀▀ ",
+            "▀ ▝▘▀▀  ▝▀▝▘▟▙      ▀ ▝▘▝▀▘ ",
+            "                            ",
+            "        █▀▙ ▟▀▙ █▗▐▌        ",
+            "        █ █ █ █ ███▌        ",
+            "        ▀ ▀ ▝▀▘ ▝▘▀         ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_wrap_justify() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("a b c d e")])
+            .justify(true)
+            .wrap(true)
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "         ▜▌           ",
+            "▝▀▙      ▐▙▄      ▟▀▙ ",
+            "▟▀█      ▐▌▐▌     █ ▄ ",
+            "▝▀▝▘     ▀▝▀      ▝▀▘ ",
+            " ▝█                   ",
+            "▗▄█     ▟▀▙           ",
+            "█ █     █▀▀           ",
+            "▝▀▝▘    ▝▀▘           ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn shrink_size() -> Result<()> {
+        // "Hi" is 16x8 font pixels
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::HalfHeight)
+            .overflow(Overflow::Shrink)
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        let shrink = |width, height| {
+            big_text.shrink_size(Rect::new(0, 0, width, height), &big_text.layout_lines())
+        };
+        assert_eq!(shrink(16, 4), PixelSize::HalfHeight);
+        assert_eq!(shrink(100, 100), PixelSize::HalfHeight);
+        assert_eq!(shrink(8, 8), PixelSize::HalfWidth);
+        assert_eq!(shrink(16, 3), PixelSize::ThirdHeight);
+        assert_eq!(shrink(8, 4), PixelSize::Quadrant);
+        assert_eq!(shrink(8, 3), PixelSize::Sextant);
+        assert_eq!(shrink(8, 2), PixelSize::Braille);
+        assert_eq!(shrink(1, 1), PixelSize::Braille);
+        // the pixel sizes are the same ones that auto fit considers
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .overflow(Overflow::Shrink)
+            .auto_fit_pixel_sizes([PixelSize::Full, PixelSize::Quadrant, PixelSize::Octant])
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        let shrink = |width, height| {
+            big_text.shrink_size(Rect::new(0, 0, width, height), &big_text.layout_lines())
+        };
+        assert_eq!(shrink(8, 4), PixelSize::Quadrant);
+        assert_eq!(shrink(8, 3), PixelSize::Octant);
+        assert_eq!(shrink(1, 1), PixelSize::Octant);
+        Ok(())
+    }
+
+    #[test]
+    fn render_overflow_shrink() -> Result<()> {
+        let big_text = BigText::builder()
+            .overflow(Overflow::Shrink)
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec!["█ █  ▀  ", "█▄█ ▝█  ", "█ █  █  ", "▀ ▀ ▝▀▘ "]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_overflow_ellipsis() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .overflow(Overflow::Ellipsis)
+            .lines(vec![Line::from("Truncated"), Line::from("Fits")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "▛█▜                     ",
+            " █  ▜▟▜▖█ █             ",
+            " █  ▐▌▝▘█ █  ▄   ▄   ▄  ",
+            "▝▀▘ ▀▀  ▝▀▝▘ ▀   ▀   ▀  ",
+            "▜▛▀▌ ▀   ▟              ",
+            "▐▙▌ ▝█  ▝█▀ ▟▀▀         ",
+            "▐▌▘  █   █▗ ▝▀▙         ",
+            "▀▀  ▝▀▘  ▝▘ ▀▀▘         ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_overflow_hide() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .overflow(Overflow::Hide)
+            .alignment(Alignment::Right)
+            .lines(vec![Line::from("Hidden"), Line::from("Gone")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 18, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "  █ █  ▀   ▝█  ▝█ ",
+            "  █▄█ ▝█  ▗▄█ ▗▄█ ",
+            "  █ █  █  █ █ █ █ ",
+            "  ▀ ▀ ▝▀▘ ▝▀▝▘▝▀▝▘",
+            "                  ",
+            "                  ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn layout_letter_spacing() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .letter_spacing(1)
+            .lines(vec![Line::from("abc")])
+            .build()?;
+        // each glyph is 8 pixels wide plus 1 pixel of spacing, so the glyphs share cells
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 20, 4)),
+            [vec![
+                Rect::new(0, 0, 5, 4),
+                Rect::new(4, 0, 5, 4),
+                Rect::new(9, 0, 4, 4),
+            ]]
+        );
+        let big_text = BigText::builder()
+            .letter_spacing(-2)
+            .scale(2)
+            .lines(vec![Line::from("ab")])
+            .build()?;
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 40, 16)),
+            [vec![Rect::new(0, 0, 12, 16), Rect::new(12, 0, 16, 16)]]
+        );
+        assert_eq!(big_text.size(), Size::new(28, 16));
+        Ok(())
+    }
+
+    #[test]
+    fn layout_line_spacing() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::HalfHeight)
+            .line_spacing(-1)
+            .lines(vec![Line::from("a"), Line::from("b"), Line::from("c")])
+            .build()?;
+        // each line is 8 pixels high less 1 pixel of spacing, so the lines share cells
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 8, 20)),
+            [
+                vec![Rect::new(0, 0, 8, 4)],
+                vec![Rect::new(0, 3, 8, 5)],
+                vec![Rect::new(0, 7, 8, 4)],
+            ]
+        );
+        assert_eq!(big_text.size(), Size::new(8, 11));
+        let big_text = BigText::builder()
+            .line_spacing(2)
+            .lines(vec![Line::from("a"), Line::from("b")])
+            .build()?;
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 8, 20)),
+            [vec![Rect::new(0, 0, 8, 8)], vec![Rect::new(0, 10, 8, 8)]]
+        );
+        assert_eq!(big_text.size(), Size::new(8, 18));
+        Ok(())
+    }
+
+    #[test]
+    fn render_letter_spacing() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .letter_spacing(-1)
+            .lines(vec![Line::from("Tight")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "▛█▜ ▝▘    ▝█   ▟    ",
+            " █  ▜▌ ▟▀▟▘█▞▙▝█▀   ",
+            " █  ▐▌ ▜▄█ █ █ █▗   ",
+            "▝▀▘ ▀▀ ▄▄▛▝▀ ▀ ▝▘   ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_line_spacing() -> Result<()> {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::HalfHeight)
+            .line_spacing(-1)
+            .lines(vec![Line::from("Hi").red(), Line::from("Yo").blue()])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 8));
+        big_text.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![
+            "██  ██    ▀▀    ",
+            "██▄▄██   ▀██    ",
+            "██  ██    ██    ",
+            "██  ██   ▀▀▀▀   ",
+            "██  ██   ▄▄▄▄   ",
+            " ▀██▀   ██  ██  ",
+            " ▄██▄   ▀█▄▄█▀  ",
+            "                ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 16, 3), Style::new().red());
+        expected.set_style(Rect::new(0, 3, 16, 5), Style::new().blue());
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn layout_sub_cell() -> Result<()> {
+        let big_text = BigText::builder()
+            .proportional(true)
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("il")])
+            .build()?;
+        // "i" and "l" are 4 pixels wide plus 1 pixel of spacing, so "l" starts in the middle of
+        // the last cell of "i"
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 10, 4)),
+            [vec![Rect::new(0, 0, 3, 4), Rect::new(2, 0, 3, 4)]]
+        );
+        assert_eq!(big_text.width(), 5);
+        // the alignment offset is also measured in pixels
+        let big_text = BigText::builder()
+            .proportional(true)
+            .pixel_size(PixelSize::Quadrant)
+            .alignment(Alignment::Right)
+            .lines(vec![Line::from("i")])
+            .build()?;
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 4, 4)),
+            [vec![Rect::new(1, 0, 3, 4)]]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn render_sub_cell() -> Result<()> {
+        let big_text = BigText::builder()
+            .proportional(true)
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Hill")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "█ █ ▀ ▜▌▝█  ",
+            "█▄█▝█ ▐▌ █  ",
+            "█ █ █ ▐▌ █  ",
+            "▀ ▀▝▀▘▀▀▝▀▘ ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn layout_proportional_letter_spacing() -> Result<()> {
+        let big_text = BigText::builder()
+            .proportional(true)
+            .letter_spacing(2)
+            .lines(vec![Line::from("il")])
+            .build()?;
+        // "i" is 4 pixels wide plus 1 pixel of proportional spacing and 2 pixels of letter
+        // spacing, and the last glyph has no letter spacing
+        assert_eq!(
+            layout_cells(&big_text, Rect::new(0, 0, 20, 8)),
+            [vec![Rect::new(0, 0, 7, 8), Rect::new(7, 0, 5, 8)]]
+        );
+        assert_eq!(big_text.width(), 12);
+        Ok(())
+    }
 }
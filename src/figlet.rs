@@ -0,0 +1,750 @@
+use std::{cmp::min, collections::BTreeMap, error::Error, fmt, str::FromStr};
+
+use unicode_normalization::UnicodeNormalization;
+
+/// The characters that every FIGlet font must define, in the order they appear in the file.
+const REQUIRED_CHARS: [char; 7] = ['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß'];
+
+/// Bits of the `full_layout` header field.
+const FULL_LAYOUT_FITTING: i32 = 64;
+const FULL_LAYOUT_SMUSHING: i32 = 128;
+
+/// Horizontal smushing rules.
+const RULE_EQUAL: u8 = 1;
+const RULE_UNDERSCORE: u8 = 2;
+const RULE_HIERARCHY: u8 = 4;
+const RULE_OPPOSITE_PAIR: u8 = 8;
+const RULE_BIG_X: u8 = 16;
+const RULE_HARDBLANK: u8 = 32;
+
+/// A [FIGlet] font loaded from a `.flf` file.
+///
+/// Unlike a [`Font`](crate::Font), a FIGlet font is made of ASCII art rather than pixels, so each
+/// character of the art is drawn into a single cell and the [`PixelSize`](crate::PixelSize) of
+/// the widget has no effect. Characters are placed next to each other using the horizontal layout
+/// (full width, fitting or smushing) and smushing rules from the font header.
+///
+/// Use [`BigTextBuilder::figlet_font`](crate::BigTextBuilder::figlet_font) to render text with a
+/// FIGlet font.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tui_big_text::{BigText, FigletFont};
+///
+/// let source = std::fs::read_to_string("/usr/share/figlet/standard.flf")?;
+/// let font = FigletFont::parse(&source)?;
+/// let big_text = BigText::builder()
+///     .figlet_font(&font)
+///     .lines(vec!["FIGlet".into()])
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [FIGlet]: http://www.figlet.org/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FigletFont {
+    hardblank: char,
+    height: u16,
+    baseline: u16,
+    layout: HorizontalLayout,
+    chars: BTreeMap<char, Vec<Vec<char>>>,
+}
+
+/// How characters are placed next to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum HorizontalLayout {
+    /// Each character occupies its full width.
+    FullWidth,
+    /// Characters are moved together until they touch.
+    Fitting,
+    /// Characters are moved together until they overlap by one column, where the overlapping
+    /// characters are merged using the given rules. No rules means universal smushing.
+    Smushing(u8),
+}
+
+impl FigletFont {
+    /// Parses a font from the contents of a FIGlet font (`.flf`) file.
+    pub fn parse(source: &str) -> Result<Self, FigletError> {
+        Parser::new(source).parse()
+    }
+
+    /// The height of each character in rows.
+    pub const fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// The number of rows from the top of a character to the baseline.
+    pub const fn baseline(&self) -> u16 {
+        self.baseline
+    }
+
+    /// Returns whether the font has a character for the given grapheme.
+    pub fn contains(&self, grapheme: &str) -> bool {
+        self.fig_char(grapheme).is_some()
+    }
+
+    /// Looks up the FIGcharacter for a grapheme. Graphemes made up of several characters use the
+    /// character of their NFC normalized form if it is a single character, and otherwise the
+    /// character of their first character, as FIGcharacters can't be combined.
+    fn fig_char(&self, grapheme: &str) -> Option<&Vec<Vec<char>>> {
+        let mut chars = grapheme.chars();
+        let c = chars.next()?;
+        if chars.next().is_none() {
+            return self.chars.get(&c);
+        }
+        let mut normalized = grapheme.nfc();
+        match (normalized.next(), normalized.next()) {
+            (Some(precomposed), None) => self.chars.get(&precomposed),
+            _ => None,
+        }
+        .or_else(|| self.chars.get(&c))
+    }
+
+    /// Lays out a line of graphemes into rows of art, where each cell holds the character to draw
+    /// and the index of the grapheme it belongs to. Graphemes that are missing from the font are
+    /// skipped.
+    pub(crate) fn layout_line<'a>(
+        &self,
+        graphemes: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<Vec<(char, usize)>> {
+        let mut rows = vec![Vec::new(); self.height as usize];
+        let mut previous_width = 0;
+        for (index, grapheme) in graphemes.into_iter().enumerate() {
+            let Some(fig_char) = self.fig_char(grapheme) else {
+                continue;
+            };
+            let width = fig_char.first().map_or(0, Vec::len);
+            let overlap = self.overlap(&rows, fig_char, previous_width, width);
+            for (row, fig_row) in rows.iter_mut().zip(fig_char) {
+                let start = row.len() as isize - overlap as isize;
+                for (position, &right) in (start..).zip(fig_row) {
+                    match usize::try_from(position).ok().and_then(|p| row.get_mut(p)) {
+                        Some(cell) if position < start + overlap as isize => {
+                            *cell = match (cell.0, right) {
+                                (_, ' ') => *cell,
+                                (' ', _) => (right, index),
+                                (left, _) => {
+                                    let smushed = self.smush(left, right, previous_width, width);
+                                    (smushed.unwrap_or(right), index)
+                                }
+                            };
+                        }
+                        _ if position < 0 => {}
+                        _ => row.push((right, index)),
+                    }
+                }
+            }
+            previous_width = width;
+        }
+        for cell in rows.iter_mut().flatten() {
+            if cell.0 == self.hardblank {
+                cell.0 = ' ';
+            }
+        }
+        rows
+    }
+
+    /// The number of columns that the next character can overlap the current line by.
+    fn overlap(
+        &self,
+        rows: &[Vec<(char, usize)>],
+        fig_char: &[Vec<char>],
+        previous_width: usize,
+        width: usize,
+    ) -> usize {
+        if self.layout == HorizontalLayout::FullWidth || rows.iter().all(Vec::is_empty) {
+            return 0;
+        }
+        let mut overlap = width;
+        for (row, fig_row) in rows.iter().zip(fig_char) {
+            let line_border = row.iter().rposition(|&(c, _)| c != ' ');
+            let char_border = fig_row.iter().position(|&c| c != ' ');
+            let mut amount = char_border.unwrap_or(width)
+                + line_border.map_or(row.len(), |border| row.len() - 1 - border);
+            if let (Some(line_border), Some(char_border)) = (line_border, char_border) {
+                let left = row[line_border].0;
+                let right = fig_row[char_border];
+                if self.smush(left, right, previous_width, width).is_some() {
+                    amount += 1;
+                }
+            }
+            overlap = min(overlap, amount);
+        }
+        overlap
+    }
+
+    /// Merges two overlapping characters, or returns `None` if they can't be merged.
+    fn smush(
+        &self,
+        left: char,
+        right: char,
+        left_width: usize,
+        right_width: usize,
+    ) -> Option<char> {
+        if left == ' ' {
+            return Some(right);
+        }
+        if right == ' ' {
+            return Some(left);
+        }
+        if left_width < 2 || right_width < 2 {
+            return None;
+        }
+        let HorizontalLayout::Smushing(rules) = self.layout else {
+            return None;
+        };
+        let hardblank = self.hardblank;
+        if rules == 0 {
+            // universal smushing: the right character wins, except over hardblanks
+            return Some(if right == hardblank { left } else { right });
+        }
+        if rules & RULE_HARDBLANK != 0 && left == hardblank && right == hardblank {
+            return Some(left);
+        }
+        if left == hardblank || right == hardblank {
+            return None;
+        }
+        if rules & RULE_EQUAL != 0 && left == right {
+            return Some(left);
+        }
+        if rules & RULE_UNDERSCORE != 0 {
+            const BORDERS: &str = "|/\\[]{}()<>";
+            if left == '_' && BORDERS.contains(right) {
+                return Some(right);
+            }
+            if right == '_' && BORDERS.contains(left) {
+                return Some(left);
+            }
+        }
+        if rules & RULE_HIERARCHY != 0 {
+            if let (Some(left_class), Some(right_class)) =
+                (hierarchy_class(left), hierarchy_class(right))
+            {
+                if left_class > right_class {
+                    return Some(left);
+                }
+                if right_class > left_class {
+                    return Some(right);
+                }
+            }
+        }
+        if rules & RULE_OPPOSITE_PAIR != 0 {
+            if let ('[', ']') | (']', '[') | ('{', '}') | ('}', '{') | ('(', ')') | (')', '(') =
+                (left, right)
+            {
+                return Some('|');
+            }
+        }
+        if rules & RULE_BIG_X != 0 {
+            match (left, right) {
+                ('/', '\\') => return Some('|'),
+                ('\\', '/') => return Some('Y'),
+                ('>', '<') => return Some('X'),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// The class of a character for the hierarchy smushing rule, where a higher class replaces a
+/// lower one.
+fn hierarchy_class(c: char) -> Option<u8> {
+    match c {
+        '|' => Some(1),
+        '/' | '\\' => Some(2),
+        '[' | ']' => Some(3),
+        '{' | '}' => Some(4),
+        '(' | ')' => Some(5),
+        '<' | '>' => Some(6),
+        _ => None,
+    }
+}
+
+impl FromStr for FigletFont {
+    type Err = FigletError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+/// An error that occurred while parsing a FIGlet font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FigletError {
+    line: usize,
+    kind: FigletErrorKind,
+}
+
+impl FigletError {
+    /// The line number (starting at 1) where the error occurred.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The kind of error that occurred.
+    pub const fn kind(&self) -> &FigletErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for FigletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl Error for FigletError {}
+
+/// The kind of a [`FigletError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FigletErrorKind {
+    /// The file does not start with the `flf2a` signature.
+    InvalidSignature,
+    /// A header field is missing or invalid.
+    InvalidHeader(&'static str),
+    /// The file ended before all required characters were defined.
+    UnexpectedEof,
+    /// The code tag of a character is not a valid number.
+    InvalidCodeTag(String),
+}
+
+impl fmt::Display for FigletErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSignature => write!(f, "missing `flf2a` signature"),
+            Self::InvalidHeader(field) => write!(f, "invalid header field `{field}`"),
+            Self::UnexpectedEof => write!(f, "unexpected end of file"),
+            Self::InvalidCodeTag(tag) => write!(f, "invalid code tag `{tag}`"),
+        }
+    }
+}
+
+struct Parser<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            lines: source.lines().enumerate(),
+            line: 0,
+        }
+    }
+
+    fn parse(mut self) -> Result<FigletFont, FigletError> {
+        let header = self.next_line()?;
+        let hardblank = header
+            .strip_prefix("flf2a")
+            .and_then(|rest| rest.chars().next())
+            .ok_or(self.error(FigletErrorKind::InvalidSignature))?;
+        let mut fields = header.split_whitespace().skip(1);
+        let height: u16 = self.field(fields.next(), "height")?;
+        let baseline: u16 = self.field(fields.next(), "baseline")?;
+        let _max_length: usize = self.field(fields.next(), "max_length")?;
+        let old_layout: i32 = self.field(fields.next(), "old_layout")?;
+        let comment_lines: usize = self.field(fields.next(), "comment_lines")?;
+        let _print_direction = fields.next();
+        let full_layout = fields
+            .next()
+            .map(|field| self.field::<i32>(Some(field), "full_layout"))
+            .transpose()?;
+        if height == 0 {
+            return Err(self.error(FigletErrorKind::InvalidHeader("height")));
+        }
+        let layout = match full_layout {
+            Some(layout) if layout & FULL_LAYOUT_SMUSHING != 0 => {
+                HorizontalLayout::Smushing((layout & 63) as u8)
+            }
+            Some(layout) if layout & FULL_LAYOUT_FITTING != 0 => HorizontalLayout::Fitting,
+            Some(_) => HorizontalLayout::FullWidth,
+            None if old_layout < 0 => HorizontalLayout::FullWidth,
+            None if old_layout == 0 => HorizontalLayout::Fitting,
+            None => HorizontalLayout::Smushing((old_layout & 63) as u8),
+        };
+        for _ in 0..comment_lines {
+            self.next_line()?;
+        }
+
+        let mut chars = BTreeMap::new();
+        for c in (' '..='~').chain(REQUIRED_CHARS) {
+            // some fonts omit the German characters, so only the ASCII ones are mandatory
+            if !c.is_ascii() && self.is_eof() {
+                break;
+            }
+            chars.insert(c, self.fig_char(height)?);
+        }
+        while let Some(tag) = self.next_tag() {
+            let code = self.code_tag(tag)?;
+            let fig_char = self.fig_char(height)?;
+            if let Some(c) = code {
+                chars.insert(c, fig_char);
+            }
+        }
+        Ok(FigletFont {
+            hardblank,
+            height,
+            baseline,
+            layout,
+            chars,
+        })
+    }
+
+    /// Reads the rows of a character, removing the end marks and padding the rows to the same
+    /// width.
+    fn fig_char(&mut self, height: u16) -> Result<Vec<Vec<char>>, FigletError> {
+        let mut rows = (0..height)
+            .map(|_| {
+                let line = self.next_line()?.trim_end();
+                let row = match line.chars().last() {
+                    Some(end_mark) => line.trim_end_matches(end_mark),
+                    None => line,
+                };
+                Ok(row.chars().collect::<Vec<_>>())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, ' ');
+        }
+        Ok(rows)
+    }
+
+    /// Returns the next non-empty line, which starts a code tagged character.
+    fn next_tag(&mut self) -> Option<&'a str> {
+        loop {
+            let line = self.next_line().ok()?;
+            if !line.trim().is_empty() {
+                return Some(line);
+            }
+        }
+    }
+
+    /// Parses a code tag in decimal, octal (`0` prefix) or hexadecimal (`0x` prefix). Negative
+    /// codes are valid but don't map to a character.
+    fn code_tag(&self, tag: &str) -> Result<Option<char>, FigletError> {
+        let code = tag.split_whitespace().next().unwrap_or_default();
+        let (negative, digits) = match code.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, code),
+        };
+        let value = if let Some(hex) = digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))
+        {
+            u32::from_str_radix(hex, 16)
+        } else if digits.len() > 1 && digits.starts_with('0') {
+            u32::from_str_radix(&digits[1..], 8)
+        } else {
+            digits.parse()
+        };
+        let value =
+            value.map_err(|_| self.error(FigletErrorKind::InvalidCodeTag(code.to_string())))?;
+        Ok(if negative {
+            None
+        } else {
+            char::from_u32(value)
+        })
+    }
+
+    fn field<T: FromStr>(&self, field: Option<&str>, name: &'static str) -> Result<T, FigletError> {
+        field
+            .and_then(|field| field.parse().ok())
+            .ok_or(self.error(FigletErrorKind::InvalidHeader(name)))
+    }
+
+    fn next_line(&mut self) -> Result<&'a str, FigletError> {
+        let (index, line) = self
+            .lines
+            .next()
+            .ok_or(self.error(FigletErrorKind::UnexpectedEof))?;
+        self.line = index + 1;
+        Ok(line)
+    }
+
+    fn is_eof(&self) -> bool {
+        self.lines.clone().all(|(_, line)| line.trim().is_empty())
+    }
+
+    fn error(&self, kind: FigletErrorKind) -> FigletError {
+        FigletError {
+            line: self.line,
+            kind,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::prelude::*;
+
+    use super::*;
+    use crate::{BigText, VerticalAlignment};
+
+    type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// Builds a font with 3 row high characters, where every character that isn't given is a
+    /// single space column.
+    fn font(header: &str, chars: &[(char, [&str; 3])]) -> String {
+        let mut source = format!("{header}\ncomment line\n");
+        let lookup = |c: char| {
+            chars
+                .iter()
+                .find(|(fig_c, _)| *fig_c == c)
+                .map(|(_, rows)| rows)
+        };
+        for c in ' '..='~' {
+            let rows = lookup(c).copied().unwrap_or([" ", " ", " "]);
+            source.push_str(&format!("{}@\n{}@\n{}@@\n", rows[0], rows[1], rows[2]));
+        }
+        for c in REQUIRED_CHARS {
+            let rows = lookup(c).copied().unwrap_or([" ", " ", " "]);
+            source.push_str(&format!("{}@\n{}@\n{}@@\n", rows[0], rows[1], rows[2]));
+        }
+        for &(c, rows) in chars.iter().filter(|(c, _)| !c.is_ascii()) {
+            source.push_str(&format!("{} NAME\n", c as u32));
+            source.push_str(&format!("{}@\n{}@\n{}@@\n", rows[0], rows[1], rows[2]));
+        }
+        source
+    }
+
+    const CHARS: &[(char, [&str; 3])] = &[
+        ('H', ["|  |", "|--|", "|  |"]),
+        ('i', ["  ", "o ", "| "]),
+        ('/', ["  /", " / ", "/  "]),
+        ('\\', ["\\  ", " \\ ", "  \\"]),
+        ('_', ["   ", "   ", "___"]),
+        ('$', ["$$", "$$", "$$"]),
+        ('λ', ["\\ ", " \\", "/\\"]),
+    ];
+
+    fn render(font: &FigletFont, text: &str) -> Vec<String> {
+        let graphemes = text.chars().map(String::from).collect::<Vec<_>>();
+        font.layout_line(graphemes.iter().map(String::as_str))
+            .into_iter()
+            .map(|row| row.into_iter().map(|(c, _)| c).collect())
+            .collect()
+    }
+
+    #[test]
+    fn parse() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 -1 1", CHARS))?;
+        assert_eq!(font.height(), 3);
+        assert_eq!(font.baseline(), 2);
+        assert_eq!(font.hardblank, '$');
+        assert_eq!(font.layout, HorizontalLayout::FullWidth);
+        assert!(font.contains("H"));
+        assert!(font.contains("λ"));
+        assert!(font.contains("Ä"));
+        assert!(font.contains("A\u{308}"));
+        assert!(font.contains("H\u{308}"));
+        assert!(!font.contains("€"));
+        assert!(!font.contains(""));
+        assert_eq!(
+            font.chars[&'i'],
+            [vec![' ', ' '], vec!['o', ' '], vec!['|', ' ']]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_without_german_characters() -> Result<()> {
+        let source = font("flf2a$ 3 2 4 -1 1", CHARS);
+        let ascii_only = source
+            .lines()
+            .take(2 + 95 * 3)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let font = FigletFont::parse(&ascii_only)?;
+        assert!(font.contains("~"));
+        assert!(!font.contains("Ä"));
+        Ok(())
+    }
+
+    #[test]
+    fn layout_modes() -> Result<()> {
+        for (header, expected) in [
+            ("flf2a$ 3 2 4 -1 1", HorizontalLayout::FullWidth),
+            ("flf2a$ 3 2 4 0 1", HorizontalLayout::Fitting),
+            ("flf2a$ 3 2 4 15 1", HorizontalLayout::Smushing(15)),
+            ("flf2a$ 3 2 4 -1 1 0 64", HorizontalLayout::Fitting),
+            ("flf2a$ 3 2 4 -1 1 0 128", HorizontalLayout::Smushing(0)),
+            ("flf2a$ 3 2 4 0 1 0 0", HorizontalLayout::FullWidth),
+        ] {
+            let font = FigletFont::parse(&font(header, CHARS))?;
+            assert_eq!(font.layout, expected, "{header}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn full_width() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 -1 1", CHARS))?;
+        assert_eq!(
+            render(&font, "Hi H"),
+            ["|  |   |  |", "|--|o  |--|", "|  ||  |  |"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fitting() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 0 1", CHARS))?;
+        assert_eq!(
+            render(&font, "HiH"),
+            ["|  | |  |", "|--|o|--|", "|  |||  |"]
+        );
+        assert_eq!(render(&font, "/\\"), ["  /\\  ", " /  \\ ", "/    \\"]);
+        Ok(())
+    }
+
+    #[test]
+    fn universal_smushing() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 -1 1 0 128", CHARS))?;
+        assert_eq!(render(&font, "HH"), ["|  |  |", "|--|--|", "|  |  |"]);
+        Ok(())
+    }
+
+    #[test]
+    fn smushing_rules() -> Result<()> {
+        let equal = FigletFont::parse(&font("flf2a$ 3 2 4 1 1", CHARS))?;
+        assert_eq!(render(&equal, "HH"), ["|  |  |", "|--|--|", "|  |  |"]);
+        assert_eq!(render(&equal, "/\\"), ["  /\\  ", " /  \\ ", "/    \\"]);
+
+        let big_x = FigletFont::parse(&font("flf2a$ 3 2 4 16 1", CHARS))?;
+        assert_eq!(render(&big_x, "/\\"), ["  |  ", " / \\ ", "/   \\"]);
+
+        let underscore = FigletFont::parse(&font("flf2a$ 3 2 4 2 1", CHARS))?;
+        assert_eq!(render(&underscore, "_H"), ["  |  |", "  |--|", "__|  |"]);
+
+        let hardblank = FigletFont::parse(&font("flf2a$ 3 2 4 32 1", CHARS))?;
+        assert_eq!(render(&hardblank, "$$"), ["   ", "   ", "   "]);
+        Ok(())
+    }
+
+    #[test]
+    fn code_tagged_characters() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 -1 1", CHARS))?;
+        assert_eq!(render(&font, "λ"), ["\\ ", " \\", "/\\"]);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() -> Result<()> {
+        let error = FigletFont::parse("flf1a$ 3 2 4 -1 1\n").unwrap_err();
+        assert_eq!(
+            (error.line(), error.kind()),
+            (1, &FigletErrorKind::InvalidSignature)
+        );
+
+        let error = FigletFont::parse("flf2a$ 3 x 4 -1 1\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: invalid header field `baseline`");
+
+        let source = font("flf2a$ 3 2 4 -1 1", CHARS);
+        let truncated = source.lines().take(20).collect::<Vec<_>>().join("\n");
+        let error = FigletFont::parse(&truncated).unwrap_err();
+        assert_eq!(
+            (error.line(), error.kind()),
+            (20, &FigletErrorKind::UnexpectedEof)
+        );
+
+        let invalid_tag = format!("{source}0xZZ\n a@\n b@\n c@@\n");
+        let error = FigletFont::parse(&invalid_tag).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &FigletErrorKind::InvalidCodeTag("0xZZ".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn render_big_text() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 0 1", CHARS))?;
+        let big_text = BigText::builder()
+            .figlet_font(&font)
+            .alignment(Alignment::Center)
+            .lines(vec![
+                Line::from(vec!["H".red(), "i".green()]),
+                Line::from("iH"),
+            ])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        big_text.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![
+            "  |  |    ",
+            "  |--|o   ",
+            "  |  ||   ",
+            "    |  |  ",
+            "   o|--|  ",
+        ]);
+        expected.set_style(Rect::new(2, 0, 4, 3), Style::new().red());
+        expected.set_style(Rect::new(6, 0, 2, 3), Style::new().green());
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_big_text_line_style() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 0 1", CHARS))?;
+        let big_text = BigText::builder()
+            .figlet_font(&font)
+            .style(Style::new().bold())
+            .lines(vec![
+                Line::from(vec!["H".red(), " ".into(), "i".into()]).italic()
+            ])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+        big_text.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(vec!["|  |      ", "|--|o     ", "|  ||     ", "          "]);
+        expected.set_style(Rect::new(0, 0, 6, 3), Style::new().bold().italic());
+        expected.set_style(Rect::new(0, 0, 4, 3), Style::new().red());
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_big_text_line_alignment() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 0 1", CHARS))?;
+        let big_text = BigText::builder()
+            .figlet_font(&font)
+            .lines(vec![Line::from("H"), Line::from("H").right_aligned()])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "|  |  ", "|--|  ", "|  |  ", "  |  |", "  |--|", "  |  |",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn render_big_text_vertical_alignment() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 0 1", CHARS))?;
+        let big_text = BigText::builder()
+            .figlet_font(&font)
+            .vertical_alignment(VerticalAlignment::Bottom)
+            .lines(vec![Line::from("Hi")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 5));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec!["      ", "      ", "|  |  ", "|--|o ", "|  || "]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn big_text_size() -> Result<()> {
+        let font = FigletFont::parse(&font("flf2a$ 3 2 4 0 1", CHARS))?;
+        let big_text = BigText::builder()
+            .figlet_font(&font)
+            .lines(vec![Line::from("Hi"), Line::from("iH")])
+            .build()?;
+        assert_eq!(big_text.size(), Size::new(6, 6));
+        Ok(())
+    }
+}
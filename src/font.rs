@@ -0,0 +1,747 @@
+use std::{borrow::Cow, collections::HashMap, fmt, hash::Hash, ops::Range, sync::OnceLock};
+
+use font8x8::UnicodeFonts;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// A source of glyphs that can be rendered by [`BigText`](crate::BigText).
+///
+/// Implement this trait to render text with a glyph set other than the default [`Font8x8`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// use tui_big_text::{Font, FontMetrics, Glyph};
+///
+/// /// A font that renders every grapheme as a solid 4x4 block.
+/// #[derive(Debug)]
+/// struct BlockFont;
+///
+/// impl Font for BlockFont {
+///     fn glyph(&self, _grapheme: &str) -> Option<Cow<'_, Glyph>> {
+///         Some(Cow::Owned(Glyph::filled(4, 4)))
+///     }
+///
+///     fn metrics(&self) -> FontMetrics {
+///         FontMetrics::new(4, 4, 4)
+///     }
+/// }
+/// ```
+pub trait Font: fmt::Debug {
+    /// Returns the glyph for the given grapheme, or `None` if the font has no glyph for it.
+    ///
+    /// Fonts only need to handle single characters. When a font has no glyph for a grapheme made
+    /// up of several characters, [`BigText`](crate::BigText) looks up its NFC normalized form,
+    /// and otherwise draws the combining marks of the grapheme on top of its first character.
+    fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>>;
+
+    /// Returns the metrics of the font.
+    fn metrics(&self) -> FontMetrics;
+
+    /// The width of a glyph cell in font pixels.
+    fn glyph_width(&self) -> u16 {
+        self.metrics().width
+    }
+
+    /// The height of a glyph cell in font pixels.
+    fn glyph_height(&self) -> u16 {
+        self.metrics().height
+    }
+
+    /// Returns the adjustment to the advance of the `left` grapheme in font pixels when it is
+    /// directly followed by the `right` grapheme.
+    ///
+    /// Negative values move the glyphs closer together, and positive values move them apart. The
+    /// default implementation does not adjust any pairs.
+    fn kerning(&self, _left: &str, _right: &str) -> i16 {
+        0
+    }
+}
+
+/// Looks up the glyph for a grapheme, including graphemes made up of several characters.
+///
+/// The glyph is looked up in this order:
+///
+/// 1. The grapheme itself, for fonts that have glyphs for character sequences.
+/// 2. The NFC normalized grapheme, so that `"e\u{301}"` uses the precomposed glyph for `"é"`.
+/// 3. The glyph of the first character, with the glyphs of the combining marks that follow it
+///    drawn on top. A combining mark that has no glyph of its own is drawn with the glyph of its
+///    spacing form (e.g. `"´"` or `"'"` for U+0301) if the font has one, and is skipped
+///    otherwise.
+///
+/// Any other characters of the grapheme, such as zero width joiners, variation selectors or the
+/// second half of a flag, are ignored. If the font has no glyph for the first character, the
+/// grapheme has no glyph.
+pub(crate) fn grapheme_glyph<'a>(font: &'a dyn Font, grapheme: &str) -> Option<Cow<'a, Glyph>> {
+    if let Some(glyph) = font.glyph(grapheme) {
+        return Some(glyph);
+    }
+    let normalized = grapheme.nfc().collect::<String>();
+    if normalized != grapheme {
+        if let Some(glyph) = font.glyph(&normalized) {
+            return Some(glyph);
+        }
+    }
+    let mut chars = normalized.chars();
+    let base = chars.next()?;
+    let mut glyph = font.glyph(base.encode_utf8(&mut [0; 4]))?;
+    for mark in chars.filter(|&c| is_combining_mark(c)) {
+        let mark_glyph = font.glyph(mark.encode_utf8(&mut [0; 4])).or_else(|| {
+            spacing_marks(mark)
+                .iter()
+                .find_map(|spacing| font.glyph(spacing.encode_utf8(&mut [0; 4])))
+        });
+        if let Some(mark_glyph) = mark_glyph {
+            glyph.to_mut().overlay(&mark_glyph);
+        }
+    }
+    Some(glyph)
+}
+
+/// Returns the spacing forms of a combining mark, which fonts are more likely to have a glyph for,
+/// in order of preference.
+fn spacing_marks(mark: char) -> &'static [char] {
+    match mark {
+        '\u{0300}' => &['`'],
+        '\u{0301}' => &['´', '\''],
+        '\u{0302}' => &['^'],
+        '\u{0303}' => &['~'],
+        '\u{0304}' => &['¯'],
+        '\u{0308}' => &['¨'],
+        '\u{030A}' => &['°'],
+        '\u{0327}' => &['¸'],
+        _ => &[],
+    }
+}
+
+/// The dimensions of the glyph cell of a [`Font`], measured in font pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontMetrics {
+    /// The width of a glyph cell.
+    pub width: u16,
+    /// The height of a glyph cell.
+    pub height: u16,
+    /// The number of rows from the top of the glyph cell to the baseline.
+    pub baseline: u16,
+}
+
+impl FontMetrics {
+    /// Creates new font metrics.
+    pub const fn new(width: u16, height: u16, baseline: u16) -> Self {
+        Self {
+            width,
+            height,
+            baseline,
+        }
+    }
+}
+
+/// A monochrome bitmap of a single glyph.
+///
+/// Pixels are addressed by column (`x`) and row (`y`), starting from the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Glyph {
+    width: u16,
+    height: u16,
+    pixels: Vec<bool>,
+}
+
+impl Glyph {
+    /// Creates a new glyph of the given size with no pixels set.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![false; width as usize * height as usize],
+        }
+    }
+
+    /// Creates a new glyph of the given size with every pixel set.
+    pub fn filled(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![true; width as usize * height as usize],
+        }
+    }
+
+    /// The width of the glyph in pixels.
+    pub const fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// The height of the glyph in pixels.
+    pub const fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns whether the pixel at the given position is set.
+    ///
+    /// Positions outside of the glyph are never set.
+    pub fn pixel(&self, x: u16, y: u16) -> bool {
+        x < self.width && y < self.height && self.pixels[self.index(x, y)]
+    }
+
+    /// Sets or clears the pixel at the given position.
+    ///
+    /// Positions outside of the glyph are ignored.
+    pub fn set_pixel(&mut self, x: u16, y: u16, value: bool) {
+        if x < self.width && y < self.height {
+            let index = self.index(x, y);
+            self.pixels[index] = value;
+        }
+    }
+
+    /// Sets every pixel that is set in the other glyph, which is aligned to the top left corner.
+    ///
+    /// Pixels of the other glyph that are outside of this glyph are ignored.
+    pub fn overlay(&mut self, other: &Glyph) {
+        for y in 0..other.height.min(self.height) {
+            for x in 0..other.width.min(self.width) {
+                if other.pixel(x, y) {
+                    self.set_pixel(x, y, true);
+                }
+            }
+        }
+    }
+
+    /// Returns the range of columns between the leftmost and rightmost set pixels, or `None` if
+    /// no pixel is set.
+    pub fn inked_columns(&self) -> Option<Range<u16>> {
+        let inked = |x: &u16| (0..self.height).any(|y| self.pixel(*x, y));
+        let start = (0..self.width).find(inked)?;
+        let end = (0..self.width).rev().find(inked)?;
+        Some(start..end + 1)
+    }
+
+    /// Returns a copy of the glyph that is twice as wide, with each column drawn twice.
+    pub(crate) fn double_width(&self) -> Glyph {
+        let mut glyph = Glyph::new(self.width.saturating_mul(2), self.height);
+        for y in 0..self.height {
+            for x in 0..glyph.width {
+                glyph.set_pixel(x, y, self.pixel(x / 2, y));
+            }
+        }
+        glyph
+    }
+
+    /// Returns the rows of the glyph as strings of `#` for set pixels and `.` for clear pixels.
+    #[cfg(test)]
+    pub(crate) fn rows(&self) -> Vec<String> {
+        (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| if self.pixel(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+}
+
+/// Converts an 8x8 bitmap in the [font8x8] format, where each byte is a row and the least
+/// significant bit is the leftmost pixel.
+///
+/// [font8x8]: https://crates.io/crates/font8x8
+impl From<[u8; 8]> for Glyph {
+    fn from(rows: [u8; 8]) -> Self {
+        let mut glyph = Glyph::new(8, 8);
+        for (y, row) in (0..).zip(rows) {
+            for x in 0..8 {
+                glyph.set_pixel(x, y, row & (1 << x) != 0);
+            }
+        }
+        glyph
+    }
+}
+
+/// The default font, using the glyphs from the [font8x8](https://crates.io/crates/font8x8)
+/// crate.
+///
+/// The font8x8 crate splits its glyphs into several tables, which are looked up in the order of
+/// the [`Font8x8Table`] variants. By default all tables are enabled. Use [`Font8x8::BASIC`] or
+/// [`Font8x8::with_table`] / [`Font8x8::without_table`] to pick which ones are used.
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_big_text::{Font8x8, Font8x8Table};
+///
+/// // Only ASCII and accented Latin characters
+/// let font = Font8x8::BASIC.with_table(Font8x8Table::Latin);
+///
+/// // Everything except the box drawing and block element characters
+/// let font = Font8x8::ALL
+///     .without_table(Font8x8Table::Box)
+///     .without_table(Font8x8Table::Block);
+///
+/// // The same tables can be collected from an iterator
+/// let font: Font8x8 = [Font8x8Table::Basic, Font8x8Table::Greek].into_iter().collect();
+///
+/// // Move pairs such as "LT" closer together
+/// let font = Font8x8::ALL.with_auto_kerning(true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Font8x8 {
+    /// Bitmask of the enabled tables, indexed by the `Font8x8Table` discriminant.
+    tables: u8,
+    auto_kerning: bool,
+}
+
+impl Font8x8 {
+    /// A font with all font8x8 tables enabled.
+    pub const ALL: Self = Self {
+        tables: u8::MAX,
+        auto_kerning: false,
+    };
+
+    /// A font with only the [`Font8x8Table::Basic`] table (ASCII) enabled.
+    pub const BASIC: Self = Self::EMPTY.with_table(Font8x8Table::Basic);
+
+    /// A font without any tables enabled.
+    pub const EMPTY: Self = Self {
+        tables: 0,
+        auto_kerning: false,
+    };
+
+    /// Returns a copy of the font with the given table enabled.
+    #[must_use]
+    pub const fn with_table(self, table: Font8x8Table) -> Self {
+        Self {
+            tables: self.tables | table.mask(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of the font with the given table disabled.
+    #[must_use]
+    pub const fn without_table(self, table: Font8x8Table) -> Self {
+        Self {
+            tables: self.tables & !table.mask(),
+            ..self
+        }
+    }
+
+    /// Returns whether the given table is enabled.
+    pub const fn has_table(&self, table: Font8x8Table) -> bool {
+        self.tables & table.mask() != 0
+    }
+
+    /// Returns a copy of the font with automatic kerning enabled or disabled.
+    ///
+    /// The font8x8 glyphs don't come with kerning information, so when this is enabled the
+    /// kerning of each pair is derived from the columns that the two glyphs occupy in each row.
+    /// The right glyph is moved to the left by the average gap between the glyphs in the rows
+    /// that both glyphs occupy, up to 2 pixels, as long as at least one empty column is left
+    /// between the set pixels of every row. Defaults to disabled.
+    #[must_use]
+    pub const fn with_auto_kerning(self, enabled: bool) -> Self {
+        Self {
+            auto_kerning: enabled,
+            ..self
+        }
+    }
+
+    /// Returns whether automatic kerning is enabled.
+    pub const fn has_auto_kerning(&self) -> bool {
+        self.auto_kerning
+    }
+
+    /// Returns the enabled tables in lookup order.
+    pub fn tables(&self) -> impl Iterator<Item = Font8x8Table> + '_ {
+        Font8x8Table::ALL
+            .into_iter()
+            .filter(|table| self.has_table(*table))
+    }
+}
+
+impl Default for Font8x8 {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl FromIterator<Font8x8Table> for Font8x8 {
+    fn from_iter<T: IntoIterator<Item = Font8x8Table>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with_table)
+    }
+}
+
+impl Font for Font8x8 {
+    fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>> {
+        let mut chars = grapheme.chars();
+        let c = chars.next()?;
+        if chars.next().is_some() {
+            return None;
+        }
+        let glyph = self.tables().find_map(|table| table.get(c))?;
+        Some(Cow::Owned(Glyph::from(glyph)))
+    }
+
+    fn metrics(&self) -> FontMetrics {
+        FontMetrics::new(8, 8, 7)
+    }
+
+    fn kerning(&self, left: &str, right: &str) -> i16 {
+        if !self.auto_kerning {
+            return 0;
+        }
+        let profiles = ink_profiles();
+        let profile = |grapheme: &str| {
+            let mut chars = grapheme.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            let bitmap = self.tables().find_map(|table| table.get(c))?;
+            profiles.get(&bitmap)
+        };
+        match (profile(left), profile(right)) {
+            (Some(left), Some(right)) => auto_kerning(left, right, 2),
+            _ => 0,
+        }
+    }
+}
+
+/// The ink profiles of every font8x8 bitmap, which are computed once so that kerning doesn't have
+/// to convert both glyphs of every pair.
+fn ink_profiles() -> &'static HashMap<[u8; 8], InkProfile> {
+    static PROFILES: OnceLock<HashMap<[u8; 8], InkProfile>> = OnceLock::new();
+    PROFILES.get_or_init(|| {
+        Font8x8Table::ALL
+            .into_iter()
+            .flat_map(Font8x8Table::bitmaps)
+            .map(|bitmap| (bitmap, InkProfile::new(&Glyph::from(bitmap))))
+            .collect()
+    })
+}
+
+/// The leftmost and rightmost set pixel of each row of a glyph, which is all that is needed to
+/// derive the kerning of a pair of glyphs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InkProfile {
+    width: u16,
+    /// The columns of the leftmost and rightmost set pixels of each row, or `None` for empty rows
+    rows: Vec<Option<(u16, u16)>>,
+    /// The range of columns between the leftmost and rightmost set pixels of the glyph
+    columns: Option<Range<u16>>,
+}
+
+impl InkProfile {
+    fn new(glyph: &Glyph) -> Self {
+        let rows = (0..glyph.height())
+            .map(|y| {
+                let first = (0..glyph.width()).find(|&x| glyph.pixel(x, y))?;
+                let last = (0..glyph.width()).rev().find(|&x| glyph.pixel(x, y))?;
+                Some((first, last))
+            })
+            .collect();
+        Self {
+            width: glyph.width(),
+            rows,
+            columns: glyph.inked_columns(),
+        }
+    }
+}
+
+/// Derives the kerning of a pair of glyphs from the columns they occupy.
+///
+/// For every row where both glyphs have set pixels, this measures the empty columns between the
+/// rightmost pixel of the left glyph and the right edge of its inked columns, plus the empty
+/// columns between the left edge of the right glyph's inked columns and its leftmost pixel. Rows
+/// where only one of the glyphs has set pixels are ignored. The right glyph moves to the left by
+/// the average of these gaps (limited to `max`), but never so far that the set pixels of a row
+/// are less than one column apart.
+fn auto_kerning(left: &InkProfile, right: &InkProfile, max: u16) -> i16 {
+    let (Some(left_columns), Some(right_columns)) = (&left.columns, &right.columns) else {
+        return 0;
+    };
+    let gaps = left
+        .rows
+        .iter()
+        .zip(&right.rows)
+        .filter_map(|(&left_row, &right_row)| {
+            let (_, left_ink) = left_row?;
+            let (right_ink, _) = right_row?;
+            Some((left_columns.end - 1 - left_ink) + (right_ink - right_columns.start))
+        })
+        .collect::<Vec<_>>();
+    let Some(&min_gap) = gaps.iter().min() else {
+        return 0;
+    };
+    let count = gaps.len() as u16;
+    let average = (gaps.iter().sum::<u16>() + count / 2) / count;
+    // the empty columns that the glyphs already have on the sides that face each other
+    let spacing = (left.width - left_columns.end) + right_columns.start;
+    let limit = (min_gap + spacing).saturating_sub(1);
+    -(average.min(limit).min(max) as i16)
+}
+
+/// The glyph tables provided by the [font8x8](https://crates.io/crates/font8x8) crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Font8x8Table {
+    /// ASCII characters (U+0000 - U+007F)
+    Basic,
+    /// Latin-1 supplement characters, such as accented letters (U+00A0 - U+00FF)
+    Latin,
+    /// Greek and Coptic characters (U+0390 - U+03C9)
+    Greek,
+    /// Box drawing characters (U+2500 - U+257F)
+    Box,
+    /// Block element characters (U+2580 - U+259F)
+    Block,
+    /// Hiragana characters (U+3040 - U+309F)
+    Hiragana,
+    /// Miscellaneous characters, such as `ƒ`, `₧` and `≤`
+    Misc,
+    /// Standard Galactic Alphabet characters (Private Use Area U+E541 - U+E55A)
+    Sga,
+}
+
+impl Font8x8Table {
+    /// All tables in lookup order.
+    pub const ALL: [Self; 8] = [
+        Self::Basic,
+        Self::Latin,
+        Self::Greek,
+        Self::Box,
+        Self::Block,
+        Self::Hiragana,
+        Self::Misc,
+        Self::Sga,
+    ];
+
+    const fn mask(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Returns the bitmaps of every character in this table.
+    fn bitmaps(self) -> impl Iterator<Item = [u8; 8]> {
+        let glyphs = match self {
+            Self::Basic => font8x8::BASIC_FONTS.iter(),
+            Self::Latin => font8x8::LATIN_FONTS.iter(),
+            Self::Greek => font8x8::GREEK_FONTS.iter(),
+            Self::Box => font8x8::BOX_FONTS.iter(),
+            Self::Block => font8x8::BLOCK_FONTS.iter(),
+            Self::Hiragana => font8x8::HIRAGANA_FONTS.iter(),
+            Self::Misc => font8x8::MISC_FONTS.iter(),
+            Self::Sga => font8x8::SGA_FONTS.iter(),
+        };
+        glyphs.map(|glyph| glyph.byte_array())
+    }
+
+    /// Returns the bitmap for the given character if it is part of this table.
+    fn get(self, c: char) -> Option<[u8; 8]> {
+        match self {
+            Self::Basic => font8x8::BASIC_FONTS.get(c),
+            Self::Latin => font8x8::LATIN_FONTS.get(c),
+            Self::Greek => font8x8::GREEK_FONTS.get(c),
+            Self::Box => font8x8::BOX_FONTS.get(c),
+            Self::Block => font8x8::BLOCK_FONTS.get(c),
+            Self::Hiragana => font8x8::HIRAGANA_FONTS.get(c),
+            Self::Misc => font8x8::MISC_FONTS.get(c),
+            Self::Sga => font8x8::SGA_FONTS.get(c),
+        }
+    }
+}
+
+/// A reference to the [`Font`] used by a [`BigText`](crate::BigText) widget.
+///
+/// Fonts are compared and hashed by identity, so two widgets are only equal if they render with
+/// the same font instance.
+#[derive(Clone, Copy)]
+pub(crate) struct FontRef<'a>(pub(crate) &'a dyn Font);
+
+impl Default for FontRef<'_> {
+    fn default() -> Self {
+        Self(&Font8x8::ALL)
+    }
+}
+
+impl fmt::Debug for FontRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FontRef<'_> {
+    fn as_ptr(&self) -> *const () {
+        (self.0 as *const dyn Font).cast()
+    }
+}
+
+impl PartialEq for FontRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ptr() == other.as_ptr()
+    }
+}
+
+impl Eq for FontRef<'_> {}
+
+impl Hash for FontRef<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_ptr().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn glyph_from_font8x8_bitmap() -> Result<()> {
+        let glyph = Glyph::from([0b0000_0001, 0b1000_0000, 0, 0, 0, 0, 0, 0xFF]);
+        assert_eq!((glyph.width(), glyph.height()), (8, 8));
+        assert!(glyph.pixel(0, 0));
+        assert!(!glyph.pixel(7, 0));
+        assert!(glyph.pixel(7, 1));
+        assert!((0..8).all(|x| glyph.pixel(x, 7)));
+        Ok(())
+    }
+
+    #[test]
+    fn glyph_pixel_out_of_bounds() -> Result<()> {
+        let mut glyph = Glyph::filled(2, 2);
+        assert!(!glyph.pixel(2, 0));
+        assert!(!glyph.pixel(0, 2));
+        glyph.set_pixel(5, 5, true);
+        assert_eq!(glyph, Glyph::filled(2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn glyph_inked_columns() -> Result<()> {
+        assert_eq!(Glyph::new(4, 4).inked_columns(), None);
+        assert_eq!(Glyph::filled(4, 4).inked_columns(), Some(0..4));
+        let glyph = Font8x8::ALL.glyph("i").ok_or("missing glyph")?;
+        assert_eq!(glyph.inked_columns(), Some(1..5));
+        Ok(())
+    }
+
+    #[test]
+    fn font8x8_auto_kerning() {
+        let font = Font8x8::ALL;
+        assert!(!font.has_auto_kerning());
+        assert_eq!(font.kerning("L", "T"), 0);
+        let font = font.with_auto_kerning(true);
+        assert!(font.has_auto_kerning());
+        assert_eq!(font.kerning("L", "T"), -1);
+        assert_eq!(font.kerning("T", "o"), -2);
+        assert_eq!(font.kerning("r", "."), -2);
+        assert_eq!(font.kerning("H", "H"), 0);
+        assert_eq!(font.kerning("A", "V"), -1);
+        assert_eq!(font.kerning("T", "y"), -2);
+        assert_eq!(font.kerning("_", "'"), 0);
+        assert_eq!(font.kerning("L", " "), 0);
+        assert_eq!(font.kerning("L", "\u{FFFF}"), 0);
+        let font = font.without_table(Font8x8Table::Greek);
+        assert!(font.has_auto_kerning());
+    }
+
+    #[test]
+    fn glyph_overlay() {
+        let mut glyph = Glyph::new(3, 3);
+        glyph.set_pixel(0, 0, true);
+        let mut other = Glyph::new(4, 2);
+        other.set_pixel(1, 1, true);
+        other.set_pixel(3, 1, true);
+        glyph.overlay(&other);
+        let mut expected = Glyph::new(3, 3);
+        expected.set_pixel(0, 0, true);
+        expected.set_pixel(1, 1, true);
+        assert_eq!(glyph, expected);
+    }
+
+    #[test]
+    fn font8x8_glyph() -> Result<()> {
+        let glyph = Font8x8::ALL.glyph("A").ok_or("missing glyph")?;
+        assert_eq!(*glyph, Glyph::from(font8x8::BASIC_FONTS.get('A').unwrap()));
+        assert_eq!(Font8x8::ALL.glyph(""), None);
+        assert_eq!(Font8x8::ALL.glyph("AB"), None);
+        Ok(())
+    }
+
+    /// Looks up a single character in the default font.
+    fn font8x8_char(c: char) -> Result<Glyph> {
+        let glyph = Font8x8::ALL.glyph(c.encode_utf8(&mut [0; 4]));
+        Ok(glyph.ok_or("missing glyph")?.into_owned())
+    }
+
+    #[test]
+    fn grapheme_glyph_normalized() -> Result<()> {
+        let font = Font8x8::ALL;
+        let glyph = grapheme_glyph(&font, "e\u{301}").ok_or("missing glyph")?;
+        assert_eq!(*glyph, font8x8_char('é')?);
+        let glyph = grapheme_glyph(&font, "A\u{30A}").ok_or("missing glyph")?;
+        assert_eq!(*glyph, font8x8_char('Å')?);
+        Ok(())
+    }
+
+    #[test]
+    fn grapheme_glyph_combining_marks() -> Result<()> {
+        let font = Font8x8::ALL;
+        let mut expected = font8x8_char('q')?;
+        expected.overlay(&font8x8_char('¨')?);
+        let glyph = grapheme_glyph(&font, "q\u{308}").ok_or("missing glyph")?;
+        assert_eq!(*glyph, expected);
+
+        // there is no glyph for "´" in font8x8, so the acute accent falls back to "'"
+        let mut expected = font8x8_char('x')?;
+        expected.overlay(&font8x8_char('\'')?);
+        let glyph = grapheme_glyph(&font, "x\u{301}").ok_or("missing glyph")?;
+        assert_eq!(*glyph, expected);
+
+        // marks without any glyph are skipped
+        let glyph = grapheme_glyph(&font, "x\u{331}").ok_or("missing glyph")?;
+        assert_eq!(*glyph, font8x8_char('x')?);
+        Ok(())
+    }
+
+    #[test]
+    fn grapheme_glyph_fallback() -> Result<()> {
+        let font = Font8x8::ALL;
+        let glyph = grapheme_glyph(&font, "\u{2502}\u{FE0F}").ok_or("missing glyph")?;
+        assert_eq!(*glyph, font8x8_char('│')?);
+        assert_eq!(grapheme_glyph(&font, "\u{1F1E9}\u{1F1EA}"), None);
+        assert_eq!(grapheme_glyph(&font, "\u{1F468}\u{200D}\u{1F469}"), None);
+        assert_eq!(grapheme_glyph(&font, ""), None);
+        Ok(())
+    }
+
+    #[test]
+    fn font8x8_all_tables() -> Result<()> {
+        let font = Font8x8::default();
+        for c in ["a", "é", "Ω", "─", "▒", "ひ", "ƒ", "\u{e541}"] {
+            assert!(font.glyph(c).is_some(), "missing glyph for {c:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn font8x8_selected_tables() -> Result<()> {
+        let font = Font8x8::BASIC.with_table(Font8x8Table::Greek);
+        assert!(font.has_table(Font8x8Table::Basic));
+        assert!(!font.has_table(Font8x8Table::Latin));
+        assert!(font.glyph("a").is_some());
+        assert!(font.glyph("Ω").is_some());
+        assert!(font.glyph("é").is_none());
+        assert!(font.without_table(Font8x8Table::Greek).glyph("Ω").is_none());
+        assert_eq!(
+            [Font8x8Table::Greek, Font8x8Table::Basic]
+                .into_iter()
+                .collect::<Font8x8>(),
+            font
+        );
+        assert_eq!(
+            font.tables().collect::<Vec<_>>(),
+            [Font8x8Table::Basic, Font8x8Table::Greek]
+        );
+        Ok(())
+    }
+}
@@ -47,7 +47,7 @@
 //! ```
 //!
 //! The [`PixelSize`] can be used to control how many character cells are used to represent a single
-//! pixel of the 8x8 font. It has six variants:
+//! pixel of the font. It has eight variants:
 //!
 //! - `Full` (default) - Each pixel is represented by a single character cell.
 //! - `HalfHeight` - Each pixel is represented by half the height of a character cell.
@@ -55,6 +55,10 @@
 //! - `Quadrant` - Each pixel is represented by a quarter of a character cell.
 //! - `ThirdHeight` - Each pixel is represented by a third of the height of a character cell.
 //! - `Sextant` - Each pixel is represented by a sixth of a character cell.
+//! - `Braille` - Each pixel is represented by a dot of a braille pattern (an eighth of a character
+//!   cell).
+//! - `Octant` - Each pixel is represented by an eighth of a character cell, using the block octants
+//!   from Unicode 16.
 //!
 //! ```rust
 //! # use tui_big_text::*;
@@ -65,7 +69,31 @@
 //!
 //! ![Pixel Size](https://vhs.charm.sh/vhs-2nLycKO16vHzqg3TxDNvq4.gif)
 //!
-//! Text can be aligned to the Left / Right / Center using the `alignment` method.
+//! The `scale` method makes each pixel of the font cover a block of pixels of the [`PixelSize`], so
+//! text can be rendered larger than with `PixelSize::Full`. The `scale_x` and `scale_y` methods set
+//! the horizontal and vertical scale separately.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().pixel_size(PixelSize::Quadrant).scale(3);
+//! BigText::builder().scale_x(2).scale_y(1);
+//! ```
+//!
+//! With `auto_fit`, the pixel size and scale are chosen to render the text as large as possible in
+//! the area, falling back to smaller pixel sizes as the area shrinks. The `auto_fit_pixel_sizes`
+//! method restricts the pixel sizes that are considered.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().auto_fit(true);
+//! BigText::builder()
+//!     .auto_fit(true)
+//!     .auto_fit_pixel_sizes([PixelSize::Full, PixelSize::Quadrant]);
+//! ```
+//!
+//! Text can be aligned to the Left / Right / Center using the `alignment` method. Lines that have
+//! their own alignment, such as `Line::from("Subtitle").right_aligned()`, are aligned with that
+//! instead.
 //!
 //! ```rust
 //! use ratatui::layout::Alignment;
@@ -77,16 +105,138 @@
 //!
 //! ![Alignment Example](https://vhs.charm.sh/vhs-1Yyr7BJ5vfmOmjYNywCNH3.gif)
 //!
+//! The lines can also be aligned to the top, center or bottom of the area by passing a
+//! [`VerticalAlignment`] to the `vertical_alignment` method.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().vertical_alignment(VerticalAlignment::Center);
+//! ```
+//!
+//! With `justify`, every line but the last is stretched to the full width of the area by spreading
+//! the leftover space between its words (or between its glyphs if it has a single word).
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().justify(true);
+//! ```
+//!
+//! Lines that are wider than the area are cut off by default. With `wrap`, they are broken between
+//! words (or between graphemes for words that are too long) onto extra lines.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().wrap(true);
+//! ```
+//!
+//! Text that still doesn't fit is handled with the [`Overflow`] passed to the `overflow` method. It
+//! can be clipped (the default), end in a big ellipsis, shrink to a smaller [`PixelSize`], or hide
+//! the glyphs and lines that don't fully fit.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().overflow(Overflow::Ellipsis);
+//! BigText::builder().overflow(Overflow::Shrink);
+//! ```
+//!
+//! The number of cells the text takes up can be measured before rendering with the `size`, `width`
+//! and `height` methods, and `width_constraint` / `height_constraint` return a matching
+//! [`Constraint`] for a [`Layout`].
+//!
+//! ```rust
+//! # use ratatui::prelude::*;
+//! # use tui_big_text::*;
+//! # fn render(frame: &mut Frame) -> Result<(), Box<dyn std::error::Error>> {
+//! let big_text = BigText::builder()
+//!     .pixel_size(PixelSize::Quadrant)
+//!     .lines(vec!["Title".into()])
+//!     .build()?;
+//! let [title, body] =
+//!     Layout::vertical([big_text.height_constraint(), Constraint::Fill(1)]).areas(frame.size());
+//! frame.render_widget(big_text, title);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! By default every glyph takes up the full width of the font's glyph cell, and wide characters
+//! such as CJK ideographs take up two cells. Glyphs that fit in a single cell are drawn twice as
+//! wide for them, and other narrower glyphs are centered. The `proportional` method trims each
+//! glyph to its set pixels instead, with `proportional_spacing` empty pixels between glyphs, so
+//! that narrow letters such as `i` and `l` don't leave large gaps. Glyphs are placed at the pixels
+//! of the [`PixelSize`] rather than at whole cells, so with the smaller pixel sizes neighbouring
+//! glyphs can share a cell.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().proportional(true).proportional_spacing(2);
+//! ```
+//!
+//! The `letter_spacing` and `line_spacing` methods add font pixels between glyphs and between lines.
+//! Negative values draw them closer together for tight headlines, and spacing that is smaller than a
+//! cell moves neighbouring glyphs or lines into the cells they share.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().letter_spacing(-1).line_spacing(2);
+//! ```
+//!
+//! The glyphs are looked up in a [`Font`]. The default is [`Font8x8`], which uses all the glyph
+//! tables from the [font8x8] crate (ASCII, Latin, Greek, Hiragana, box drawing, etc.). Bitmap fonts
+//! can be loaded at runtime from BDF files with [`BdfFont`] and from Linux console (PSF) fonts with
+//! [`PsfFont`]. Any other glyph set can be used by implementing the [`Font`] trait, which can
+//! also supply kerning adjustments for pairs of glyphs. Graphemes that the font has no glyph for are
+//! looked up in the fallback fonts, in the order they were added.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().font(&Font8x8::ALL);
+//! let greek = Font8x8::BASIC.with_table(Font8x8Table::Greek);
+//! BigText::builder().font(&greek);
+//! let kerned = Font8x8::ALL.with_auto_kerning(true);
+//! BigText::builder().font(&kerned);
+//! let hiragana = Font8x8::EMPTY.with_table(Font8x8Table::Hiragana);
+//! BigText::builder()
+//!     .font(&Font8x8::BASIC)
+//!     .fallback_font(&hiragana);
+//! ```
+//!
+//! Graphemes that the font has no glyph for are left blank by default. The `missing_glyph` method
+//! selects a [`MissingGlyph`] placeholder instead, such as a hollow box or a `?`.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().missing_glyph(MissingGlyph::Tofu);
+//! BigText::builder().missing_glyph(MissingGlyph::Char('?'));
+//! ```
+//!
+//! Text can also be rendered as ASCII art with a [FIGlet] font by passing a [`FigletFont`] to the
+//! `figlet_font` method. Alignment and styles work the same way as with pixel fonts.
+//!
+//! With the `truetype` feature enabled, TrueType and OpenType fonts can be rasterized at any pixel
+//! height with [`TrueTypeFont`]. The rasterized glyphs are drawn with the selected [`PixelSize`].
+//!
 //! [tui-big-text]: https://crates.io/crates/tui-big-text
 //! [Ratatui]: https://crates.io/crates/ratatui
 //! [font8x8]: https://crates.io/crates/font8x8
+//! [FIGlet]: http://www.figlet.org/
 //!
 //! <!-- Note that these links are sensitive to breaking with cargo-rdme -->
+//! [`BdfFont`]: crate::bdf::BdfFont
 //! [`BigText`]: crate::big_text::BigText
 //! [`BigText::builder`]: crate::big_text::BigText#method.builder
+//! [`Constraint`]: https://docs.rs/ratatui/latest/ratatui/layout/enum.Constraint.html
+//! [`FigletFont`]: crate::figlet::FigletFont
+//! [`Font`]: crate::font::Font
+//! [`Font8x8`]: crate::font::Font8x8
+//! [`Layout`]: https://docs.rs/ratatui/latest/ratatui/layout/struct.Layout.html
+//! [`MissingGlyph`]: crate::missing_glyph::MissingGlyph
+//! [`Overflow`]: crate::overflow::Overflow
 //! [`PixelSize`]: crate::pixel_size::PixelSize
+//! [`PsfFont`]: crate::psf::PsfFont
 //! [`render_widget`]: https://docs.rs/ratatui/latest/ratatui/struct.Frame.html#method.render_widget
 //! [`Style`]: https://docs.rs/ratatui/latest/ratatui/style/struct.Style.html
+//! [`TrueTypeFont`]: https://docs.rs/tui-big-text/latest/tui_big_text/truetype/struct.TrueTypeFont.html
+//! [`VerticalAlignment`]: crate::vertical_alignment::VerticalAlignment
 //!
 //! [Crate badge]: https://img.shields.io/crates/v/tui-big-text?logo=rust&style=for-the-badge
 //! [Docs.rs Badge]: https://img.shields.io/docsrs/tui-big-text?logo=rust&style=for-the-badge
@@ -105,8 +255,26 @@
 //! [Changelog]: https://github.com/joshka/tui-big-text/blob/main/CHANGELOG.md
 //! [Contributing]: https://github.com/joshka/tui-big-text/blob/main/CONTRIBUTING.md
 
+mod bdf;
 mod big_text;
+mod figlet;
+mod font;
+mod missing_glyph;
+mod overflow;
 mod pixel_size;
+mod psf;
+#[cfg(feature = "truetype")]
+mod truetype;
+mod vertical_alignment;
 
+pub use bdf::{BdfError, BdfErrorKind, BdfFont};
 pub use big_text::{BigText, BigTextBuilder};
+pub use figlet::{FigletError, FigletErrorKind, FigletFont};
+pub use font::{Font, Font8x8, Font8x8Table, FontMetrics, Glyph};
+pub use missing_glyph::MissingGlyph;
+pub use overflow::Overflow;
 pub use pixel_size::PixelSize;
+pub use psf::{PsfError, PsfFont};
+#[cfg(feature = "truetype")]
+pub use truetype::{TrueTypeError, TrueTypeFont};
+pub use vertical_alignment::VerticalAlignment;
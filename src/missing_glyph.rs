@@ -0,0 +1,76 @@
+use crate::font::{FontMetrics, Glyph};
+
+/// What is rendered for a grapheme that the font has no glyph for.
+///
+/// Whitespace graphemes without a glyph are always left blank.
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_big_text::{BigText, MissingGlyph};
+///
+/// BigText::builder().missing_glyph(MissingGlyph::Tofu);
+/// BigText::builder().missing_glyph(MissingGlyph::Char('?'));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum MissingGlyph {
+    /// The grapheme takes up the space of a glyph, but nothing is drawn.
+    #[default]
+    Blank,
+    /// A hollow box the size of the glyph cell, also known as "tofu".
+    Tofu,
+    /// The glyph of the given character, such as `'?'`. If the font has no glyph for the
+    /// character either, nothing is drawn.
+    Char(char),
+    /// The given glyph.
+    Glyph(Glyph),
+    /// The grapheme itself, drawn as regular text in the middle of an otherwise blank glyph cell.
+    Small,
+    /// Nothing; the grapheme is skipped and takes up no space.
+    Skip,
+}
+
+/// Creates a hollow box that fills the glyph cell from the top to the baseline, leaving the last
+/// column empty as spacing.
+pub(crate) fn tofu(metrics: FontMetrics) -> Glyph {
+    let width = metrics.width.saturating_sub(1).max(1);
+    let height = if metrics.baseline == 0 {
+        metrics.height
+    } else {
+        metrics.baseline
+    };
+    let mut glyph = Glyph::new(metrics.width, metrics.height);
+    for x in 0..width {
+        glyph.set_pixel(x, 0, true);
+        glyph.set_pixel(x, height.saturating_sub(1), true);
+    }
+    for y in 0..height {
+        glyph.set_pixel(0, y, true);
+        glyph.set_pixel(width - 1, y, true);
+    }
+    glyph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tofu_glyph() {
+        let glyph = tofu(FontMetrics::new(5, 6, 4));
+        assert_eq!(
+            glyph.rows(),
+            ["####.", "#..#.", "#..#.", "####.", ".....", "....."]
+        );
+    }
+
+    #[test]
+    fn tofu_glyph_without_baseline() {
+        assert_eq!(tofu(FontMetrics::new(2, 2, 0)), {
+            let mut glyph = Glyph::new(2, 2);
+            glyph.set_pixel(0, 0, true);
+            glyph.set_pixel(0, 1, true);
+            glyph
+        });
+    }
+}
@@ -0,0 +1,27 @@
+/// What happens to text that doesn't fit in the area the widget is rendered in.
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_big_text::{BigText, Overflow};
+///
+/// BigText::builder().overflow(Overflow::Ellipsis);
+/// BigText::builder().overflow(Overflow::Shrink);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// The text is cut off at the edges of the area, which may cut a glyph in half.
+    #[default]
+    Clip,
+    /// The glyphs at the end of a line that is too wide are replaced with a big "…" glyph, or with
+    /// three "." glyphs if no font has a glyph for "…". Lines that don't fit below the area are
+    /// cut off.
+    Ellipsis,
+    /// The first pixel size that fits the text in the area is used instead of the pixel size of
+    /// the widget, trying the `auto_fit_pixel_sizes` of the widget that come after its pixel size
+    /// in order (which by default go from the largest to the smallest). If the text doesn't fit
+    /// with any of them, it is rendered with the last one and cut off.
+    Shrink,
+    /// Glyphs and lines that don't fully fit in the area are not drawn.
+    Hide,
+}
@@ -1,27 +1,39 @@
+use crate::font::Glyph;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
 pub enum PixelSize {
     #[default]
-    /// A pixel from the 8x8 font is represented by a full character cell in the terminal.
+    /// A pixel of the glyph is represented by a full character cell in the terminal.
     Full,
-    /// A pixel from the 8x8 font is represented by a half (upper/lower) character cell in the
+    /// A pixel of the glyph is represented by a half (upper/lower) character cell in the
     /// terminal.
     HalfHeight,
-    /// A pixel from the 8x8 font is represented by a half (left/right) character cell in the
+    /// A pixel of the glyph is represented by a half (left/right) character cell in the
     /// terminal.
     HalfWidth,
-    /// A pixel from the 8x8 font is represented by a quadrant of a character cell in the
+    /// A pixel of the glyph is represented by a quadrant of a character cell in the
     /// terminal.
     Quadrant,
-    /// A pixel from the 8x8 font is represented by a third (top/middle/bottom) of a character
+    /// A pixel of the glyph is represented by a third (top/middle/bottom) of a character
     /// cell in the terminal.  
     /// *Note: depending on how the used terminal renders characters, the generated text with
     /// this PixelSize might look very strange.*
     ThirdHeight,
-    /// A pixel from the 8x8 font is represented by a sextant of a character cell in the
+    /// A pixel of the glyph is represented by a sextant of a character cell in the
     /// terminal.  
     /// *Note: depending on how the used terminal renders characters, the generated text with
     /// this PixelSize might look very strange.*
     Sextant,
+    /// A pixel of the glyph is represented by a dot of a braille pattern (2x4 dots) in a
+    /// character cell in the terminal, so an 8x8 glyph takes up 4x2 cells.  
+    /// *Note: the dots of braille patterns don't touch each other in most terminal fonts, so the
+    /// generated text looks dotted rather than solid.*
+    Braille,
+    /// A pixel of the glyph is represented by an octant (2x4) of a character cell in the
+    /// terminal, so an 8x8 glyph takes up 4x2 cells.  
+    /// *Note: the block octants were added in Unicode 16, so many terminal fonts don't have them
+    /// yet.*
+    Octant,
 }
 
 impl PixelSize {
@@ -38,79 +50,49 @@ impl PixelSize {
             PixelSize::Quadrant => (2, 2),
             PixelSize::ThirdHeight => (1, 3),
             PixelSize::Sextant => (2, 3),
+            PixelSize::Braille => (2, 4),
+            PixelSize::Octant => (2, 4),
         }
     }
 
     /// Get a symbol/char that represents the pixels at the given position with the given pixel size
-    pub(crate) fn symbol_for_position(self, glyph: &[u8; 8], row: usize, col: i32) -> char {
+    ///
+    /// Pixels that are outside of the glyph are treated as not set.
+    pub(crate) fn symbol_for_position(self, glyph: &Glyph, row: u16, col: u16) -> char {
+        let pixel = |x: u16, y: u16| u8::from(glyph.pixel(col + x, row + y));
         match self {
-            PixelSize::Full => match glyph[row] & (1 << col) {
+            PixelSize::Full => match pixel(0, 0) {
                 0 => ' ',
                 _ => '█',
             },
-            PixelSize::HalfHeight => {
-                let top = glyph[row] & (1 << col);
-                let bottom = glyph[row + 1] & (1 << col);
-                get_symbol_half_height(top, bottom)
-            }
-            PixelSize::HalfWidth => {
-                let left = glyph[row] & (1 << col);
-                let right = glyph[row] & (1 << (col + 1));
-                get_symbol_half_width(left, right)
-            }
+            PixelSize::HalfHeight => get_symbol_half_height(pixel(0, 0), pixel(0, 1)),
+            PixelSize::HalfWidth => get_symbol_half_width(pixel(0, 0), pixel(1, 0)),
             PixelSize::Quadrant => {
-                let top_left = glyph[row] & (1 << col);
-                let top_right = glyph[row] & (1 << (col + 1));
-                let bottom_left = glyph[row + 1] & (1 << col);
-                let bottom_right = glyph[row + 1] & (1 << (col + 1));
-                get_symbol_quadrant_size(top_left, top_right, bottom_left, bottom_right)
+                get_symbol_quadrant_size(pixel(0, 0), pixel(1, 0), pixel(0, 1), pixel(1, 1))
             }
             PixelSize::ThirdHeight => {
-                let top = glyph[row] & (1 << col);
-                let is_middle_available = (row + 1) < glyph.len();
-                let middle = if is_middle_available {
-                    glyph[row + 1] & (1 << col)
-                } else {
-                    0
-                };
-                let is_bottom_available = (row + 2) < glyph.len();
-                let bottom = if is_bottom_available {
-                    glyph[row + 2] & (1 << col)
-                } else {
-                    0
-                };
-                get_symbol_third_height(top, middle, bottom)
-            }
-            PixelSize::Sextant => {
-                let top_left = glyph[row] & (1 << col);
-                let top_right = glyph[row] & (1 << (col + 1));
-                let is_middle_available = (row + 1) < glyph.len();
-                let (middle_left, middle_right) = if is_middle_available {
-                    (
-                        glyph[row + 1] & (1 << col),
-                        glyph[row + 1] & (1 << (col + 1)),
-                    )
-                } else {
-                    (0, 0)
-                };
-                let is_bottom_available = (row + 2) < glyph.len();
-                let (bottom_left, bottom_right) = if is_bottom_available {
-                    (
-                        glyph[row + 2] & (1 << col),
-                        glyph[row + 2] & (1 << (col + 1)),
-                    )
-                } else {
-                    (0, 0)
-                };
-                get_symbol_sextant_size(
-                    top_left,
-                    top_right,
-                    middle_left,
-                    middle_right,
-                    bottom_left,
-                    bottom_right,
-                )
+                get_symbol_third_height(pixel(0, 0), pixel(0, 1), pixel(0, 2))
             }
+            PixelSize::Sextant => get_symbol_sextant_size(
+                pixel(0, 0),
+                pixel(1, 0),
+                pixel(0, 1),
+                pixel(1, 1),
+                pixel(0, 2),
+                pixel(1, 2),
+            ),
+            PixelSize::Braille => get_symbol_braille([
+                [pixel(0, 0), pixel(1, 0)],
+                [pixel(0, 1), pixel(1, 1)],
+                [pixel(0, 2), pixel(1, 2)],
+                [pixel(0, 3), pixel(1, 3)],
+            ]),
+            PixelSize::Octant => get_symbol_octant([
+                [pixel(0, 0), pixel(1, 0)],
+                [pixel(0, 1), pixel(1, 1)],
+                [pixel(0, 2), pixel(1, 2)],
+                [pixel(0, 3), pixel(1, 3)],
+            ]),
         }
     }
 }
@@ -206,6 +188,60 @@ fn get_symbol_sextant_size(
     SEXANT_SYMBOLS[character_index]
 }
 
+/// Get the correct unicode symbol for 2x4 "pixels", given as rows of (left, right) pixels
+fn get_symbol_braille(rows: [[u8; 2]; 4]) -> char {
+    // The braille patterns are all in order in unicode, with one bit per dot. The dots are
+    // numbered down the left column and then down the right column, except that the bottom row
+    // (dots 7 and 8) was added later and uses the two highest bits. An empty pattern is rendered
+    // as a space, like the other pixel sizes.
+    const DOT_BITS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+    let mut character_index = 0;
+    for (row, bits) in rows.iter().zip(DOT_BITS) {
+        for (&pixel, bit) in row.iter().zip(bits) {
+            if pixel > 0 {
+                character_index |= bit;
+            }
+        }
+    }
+    match character_index {
+        0 => ' ',
+        _ => char::from_u32(0x2800 + character_index).unwrap_or(' '),
+    }
+}
+
+/// Get the correct unicode symbol for 2x4 "pixels", given as rows of (left, right) pixels
+fn get_symbol_octant(rows: [[u8; 2]; 4]) -> char {
+    // We use an array here instead of directly indexing into the unicode symbols, because the
+    // octants that look like an existing block character (e.g. the quadrants, the full block and
+    // the one quarter blocks) are not part of the octant character set and the existing character
+    // is used instead.
+    const OCTANT_SYMBOLS: [char; 256] = [
+        ' ', '𜺨', '𜺫', '🮂', '𜴀', '▘', '𜴁', '𜴂', '𜴃', '𜴄', '▝', '𜴅', '𜴆', '𜴇', '𜴈', '▀', '𜴉', '𜴊',
+        '𜴋', '𜴌', '🯦', '𜴍', '𜴎', '𜴏', '𜴐', '𜴑', '𜴒', '𜴓', '𜴔', '𜴕', '𜴖', '𜴗', '𜴘', '𜴙', '𜴚', '𜴛',
+        '𜴜', '𜴝', '𜴞', '𜴟', '🯧', '𜴠', '𜴡', '𜴢', '𜴣', '𜴤', '𜴥', '𜴦', '𜴧', '𜴨', '𜴩', '𜴪', '𜴫', '𜴬',
+        '𜴭', '𜴮', '𜴯', '𜴰', '𜴱', '𜴲', '𜴳', '𜴴', '𜴵', '🮅', '𜺣', '𜴶', '𜴷', '𜴸', '𜴹', '𜴺', '𜴻', '𜴼',
+        '𜴽', '𜴾', '𜴿', '𜵀', '𜵁', '𜵂', '𜵃', '𜵄', '▖', '𜵅', '𜵆', '𜵇', '𜵈', '▌', '𜵉', '𜵊', '𜵋', '𜵌',
+        '▞', '𜵍', '𜵎', '𜵏', '𜵐', '▛', '𜵑', '𜵒', '𜵓', '𜵔', '𜵕', '𜵖', '𜵗', '𜵘', '𜵙', '𜵚', '𜵛', '𜵜',
+        '𜵝', '𜵞', '𜵟', '𜵠', '𜵡', '𜵢', '𜵣', '𜵤', '𜵥', '𜵦', '𜵧', '𜵨', '𜵩', '𜵪', '𜵫', '𜵬', '𜵭', '𜵮',
+        '𜵯', '𜵰', '𜺠', '𜵱', '𜵲', '𜵳', '𜵴', '𜵵', '𜵶', '𜵷', '𜵸', '𜵹', '𜵺', '𜵻', '𜵼', '𜵽', '𜵾', '𜵿',
+        '𜶀', '𜶁', '𜶂', '𜶃', '𜶄', '𜶅', '𜶆', '𜶇', '𜶈', '𜶉', '𜶊', '𜶋', '𜶌', '𜶍', '𜶎', '𜶏', '▗', '𜶐',
+        '𜶑', '𜶒', '𜶓', '▚', '𜶔', '𜶕', '𜶖', '𜶗', '▐', '𜶘', '𜶙', '𜶚', '𜶛', '▜', '𜶜', '𜶝', '𜶞', '𜶟',
+        '𜶠', '𜶡', '𜶢', '𜶣', '𜶤', '𜶥', '𜶦', '𜶧', '𜶨', '𜶩', '𜶪', '𜶫', '▂', '𜶬', '𜶭', '𜶮', '𜶯', '𜶰',
+        '𜶱', '𜶲', '𜶳', '𜶴', '𜶵', '𜶶', '𜶷', '𜶸', '𜶹', '𜶺', '𜶻', '𜶼', '𜶽', '𜶾', '𜶿', '𜷀', '𜷁', '𜷂',
+        '𜷃', '𜷄', '𜷅', '𜷆', '𜷇', '𜷈', '𜷉', '𜷊', '𜷋', '𜷌', '𜷍', '𜷎', '𜷏', '𜷐', '𜷑', '𜷒', '𜷓', '𜷔',
+        '𜷕', '𜷖', '𜷗', '𜷘', '𜷙', '𜷚', '▄', '𜷛', '𜷜', '𜷝', '𜷞', '▙', '𜷟', '𜷠', '𜷡', '𜷢', '▟', '𜷣',
+        '▆', '𜷤', '𜷥', '█',
+    ];
+    let character_index = rows
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, &pixel)| pixel > 0)
+        .fold(0, |index, (bit, _)| index | (1 << bit));
+
+    OCTANT_SYMBOLS[character_index]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,13 +351,100 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn check_braille_symbols() -> Result<()> {
+        assert_eq!(get_symbol_braille([[0, 0], [0, 0], [0, 0], [0, 0]]), ' ');
+        assert_eq!(get_symbol_braille([[1, 0], [0, 0], [0, 0], [0, 0]]), '⠁');
+        assert_eq!(get_symbol_braille([[0, 0], [1, 0], [0, 0], [0, 0]]), '⠂');
+        assert_eq!(get_symbol_braille([[0, 0], [0, 0], [1, 0], [0, 0]]), '⠄');
+        assert_eq!(get_symbol_braille([[0, 1], [0, 0], [0, 0], [0, 0]]), '⠈');
+        assert_eq!(get_symbol_braille([[0, 0], [0, 1], [0, 0], [0, 0]]), '⠐');
+        assert_eq!(get_symbol_braille([[0, 0], [0, 0], [0, 1], [0, 0]]), '⠠');
+        assert_eq!(get_symbol_braille([[0, 0], [0, 0], [0, 0], [1, 0]]), '⡀');
+        assert_eq!(get_symbol_braille([[0, 0], [0, 0], [0, 0], [0, 1]]), '⢀');
+        assert_eq!(get_symbol_braille([[1, 0], [1, 0], [1, 0], [1, 0]]), '⡇');
+        assert_eq!(get_symbol_braille([[0, 1], [0, 1], [0, 1], [0, 1]]), '⢸');
+        assert_eq!(get_symbol_braille([[1, 1], [0, 0], [0, 0], [1, 1]]), '⣉');
+        assert_eq!(get_symbol_braille([[1, 1], [1, 1], [1, 1], [1, 1]]), '⣿');
+        Ok(())
+    }
+
+    #[test]
+    fn check_get_symbol_for_position_in_glyph_braille() -> Result<()> {
+        // An 8x8 glyph fits exactly in 4x2 cells, so the bottom row of cells covers the last 4
+        // rows of the glyph and pixels below it are not set
+
+        let glyph = Glyph::from([0xFFu8; 8]);
+        assert_eq!(PixelSize::Braille.symbol_for_position(&glyph, 4, 6), '⣿');
+        assert_eq!(PixelSize::Braille.symbol_for_position(&glyph, 6, 0), '⠛');
+        assert_eq!(PixelSize::Braille.symbol_for_position(&glyph, 0, 7), '⡇');
+        Ok(())
+    }
+
+    #[test]
+    fn check_octant_symbols() -> Result<()> {
+        assert_eq!(get_symbol_octant([[0, 0], [0, 0], [0, 0], [0, 0]]), ' ');
+        assert_eq!(
+            get_symbol_octant([[1, 0], [0, 0], [0, 0], [0, 0]]),
+            '\u{1CEA8}'
+        );
+        assert_eq!(
+            get_symbol_octant([[0, 1], [0, 0], [0, 0], [0, 0]]),
+            '\u{1CEAB}'
+        );
+        assert_eq!(get_symbol_octant([[1, 1], [0, 0], [0, 0], [0, 0]]), '🮂');
+        assert_eq!(
+            get_symbol_octant([[0, 0], [1, 0], [0, 0], [0, 0]]),
+            '\u{1CD00}'
+        );
+        assert_eq!(get_symbol_octant([[1, 0], [1, 0], [0, 0], [0, 0]]), '▘');
+        assert_eq!(get_symbol_octant([[0, 0], [1, 0], [1, 0], [0, 0]]), '🯦');
+        assert_eq!(get_symbol_octant([[0, 0], [0, 1], [0, 1], [0, 0]]), '🯧');
+        assert_eq!(get_symbol_octant([[1, 1], [1, 1], [1, 1], [0, 0]]), '🮅');
+        assert_eq!(
+            get_symbol_octant([[0, 0], [0, 0], [0, 0], [1, 0]]),
+            '\u{1CEA3}'
+        );
+        assert_eq!(
+            get_symbol_octant([[0, 0], [0, 0], [0, 0], [0, 1]]),
+            '\u{1CEA0}'
+        );
+        assert_eq!(get_symbol_octant([[0, 0], [0, 0], [0, 0], [1, 1]]), '▂');
+        assert_eq!(get_symbol_octant([[1, 0], [1, 0], [1, 0], [1, 0]]), '▌');
+        assert_eq!(get_symbol_octant([[0, 1], [0, 1], [1, 1], [1, 1]]), '▟');
+        assert_eq!(get_symbol_octant([[0, 0], [1, 1], [1, 1], [1, 1]]), '▆');
+        assert_eq!(
+            get_symbol_octant([[0, 1], [1, 1], [1, 1], [1, 1]]),
+            '\u{1CDE5}'
+        );
+        assert_eq!(get_symbol_octant([[1, 1], [1, 1], [1, 1], [1, 1]]), '█');
+        Ok(())
+    }
+
+    #[test]
+    fn check_octant_symbols_are_unique() -> Result<()> {
+        let symbols = (0..=255u8)
+            .map(|index| {
+                let pixel = |bit: u8| (index >> bit) & 1;
+                get_symbol_octant([
+                    [pixel(0), pixel(1)],
+                    [pixel(2), pixel(3)],
+                    [pixel(4), pixel(5)],
+                    [pixel(6), pixel(7)],
+                ])
+            })
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(symbols.len(), 256);
+        Ok(())
+    }
+
     #[test]
     fn check_get_symbol_for_position_in_glyph_third_height_defensive_middle() -> Result<()> {
         // In this test, we set all pixels of the glyph to 1 (all bytes are u8-max)
         // We expect that pixels out of the glyph-bounds are not set
         // Returned character is upper third filled only
 
-        let glyph = [0xFFu8; 8];
+        let glyph = Glyph::from([0xFFu8; 8]);
         assert_eq!(
             PixelSize::ThirdHeight.symbol_for_position(&glyph, 7, 0),
             '🬂'
@@ -335,8 +458,26 @@ mod tests {
         // We expect that pixels out of the glyph-bounds are not set
         // Returned character is upper third filled only
 
-        let glyph = [0xFFu8; 8];
+        let glyph = Glyph::from([0xFFu8; 8]);
         assert_eq!(PixelSize::Sextant.symbol_for_position(&glyph, 7, 0), '🬂');
         Ok(())
     }
+
+    #[test]
+    fn check_get_symbol_for_position_in_glyph_of_arbitrary_size() -> Result<()> {
+        // Glyphs are not limited to 8x8, so the last column of a 5 pixel wide glyph is only half
+        // covered by a quadrant and the last row of a 16 pixel high glyph is only a third of a
+        // sextant
+
+        let glyph = Glyph::filled(5, 7);
+        assert_eq!(PixelSize::Quadrant.symbol_for_position(&glyph, 6, 4), '▘');
+        assert_eq!(PixelSize::HalfWidth.symbol_for_position(&glyph, 0, 4), '▌');
+        let glyph = Glyph::filled(16, 16);
+        assert_eq!(PixelSize::Sextant.symbol_for_position(&glyph, 15, 14), '🬂');
+        assert_eq!(
+            PixelSize::ThirdHeight.symbol_for_position(&glyph, 12, 0),
+            '█'
+        );
+        Ok(())
+    }
 }
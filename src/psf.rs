@@ -0,0 +1,499 @@
+use std::{borrow::Cow, collections::HashMap, error::Error, fmt};
+
+use crate::font::{Font, FontMetrics, Glyph};
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF1_MODE512: u8 = 0x01;
+const PSF1_MODEHASTAB: u8 = 0x02;
+const PSF1_MODEHASSEQ: u8 = 0x04;
+const PSF1_SEPARATOR: u16 = 0xFFFF;
+const PSF1_START_SEQUENCE: u16 = 0xFFFE;
+
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+const PSF2_HAS_UNICODE_TABLE: u32 = 0x01;
+const PSF2_SEPARATOR: u8 = 0xFF;
+const PSF2_START_SEQUENCE: u8 = 0xFE;
+
+/// A bitmap font loaded from a [PC Screen Font][PSF] (`.psf`) file.
+///
+/// PSF is the format used by the Linux console, and the fonts in `/usr/share/consolefonts` can be
+/// loaded directly (after decompressing them if they are gzipped). Both version 1 and version 2 of
+/// the format are supported, including glyphs of any width and height.
+///
+/// If the file contains a Unicode mapping table, graphemes are looked up through it, including
+/// multi-codepoint sequences. Otherwise the glyph index is used as the code point.
+///
+/// PSF files don't record a baseline, so it is assumed to be at the bottom of the glyph cell.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tui_big_text::{BigText, PsfFont};
+///
+/// let data = std::fs::read("/usr/share/consolefonts/Lat2-Terminus16.psf")?;
+/// let font = PsfFont::parse(&data)?;
+/// let big_text = BigText::builder()
+///     .font(&font)
+///     .lines(vec!["Console".into()])
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [PSF]: https://en.wikipedia.org/wiki/PC_Screen_Font
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PsfFont {
+    metrics: FontMetrics,
+    glyphs: Vec<Glyph>,
+    unicode_table: HashMap<String, usize>,
+}
+
+impl PsfFont {
+    /// Parses a font from the contents of a PSF1 or PSF2 file.
+    pub fn parse(data: &[u8]) -> Result<Self, PsfError> {
+        if data.starts_with(&PSF2_MAGIC) {
+            Self::parse_psf2(data)
+        } else if data.starts_with(&PSF1_MAGIC) {
+            Self::parse_psf1(data)
+        } else {
+            Err(PsfError::InvalidMagic)
+        }
+    }
+
+    /// The number of glyphs in the font.
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Returns whether the font has no glyphs.
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    fn parse_psf1(data: &[u8]) -> Result<Self, PsfError> {
+        let mut reader = Reader::new(data);
+        reader.bytes(PSF1_MAGIC.len())?;
+        let mode = reader.u8()?;
+        let height = reader.u8()?;
+        if height == 0 {
+            return Err(PsfError::InvalidHeader);
+        }
+        let length = if mode & PSF1_MODE512 != 0 { 512 } else { 256 };
+        let glyphs = (0..length)
+            .map(|_| reader.glyph(8, height.into()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let unicode_table = if mode & (PSF1_MODEHASTAB | PSF1_MODEHASSEQ) != 0 {
+            psf1_unicode_table(&mut reader, length)?
+        } else {
+            identity_unicode_table(length)
+        };
+        Ok(Self {
+            metrics: FontMetrics::new(8, height.into(), height.into()),
+            glyphs,
+            unicode_table,
+        })
+    }
+
+    fn parse_psf2(data: &[u8]) -> Result<Self, PsfError> {
+        let mut reader = Reader::new(data);
+        reader.bytes(PSF2_MAGIC.len())?;
+        let _version = reader.u32()?;
+        let header_size = reader.u32()?;
+        let flags = reader.u32()?;
+        let length = reader.u32()?;
+        let glyph_size = reader.u32()?;
+        let height = reader.u32()?;
+        let width = reader.u32()?;
+        let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(PsfError::InvalidHeader);
+        };
+        if width == 0
+            || height == 0
+            || glyph_size != u32::from(width.div_ceil(8)) * u32::from(height)
+        {
+            return Err(PsfError::InvalidHeader);
+        }
+        reader.seek(header_size as usize)?;
+        // check the length against the data before allocating the glyphs
+        if u64::from(length) * u64::from(glyph_size) > reader.remaining() as u64 {
+            return Err(PsfError::UnexpectedEof);
+        }
+        let length = length as usize;
+        let glyphs = (0..length)
+            .map(|_| reader.glyph(width, height))
+            .collect::<Result<Vec<_>, _>>()?;
+        let unicode_table = if flags & PSF2_HAS_UNICODE_TABLE != 0 {
+            psf2_unicode_table(&mut reader, length)?
+        } else {
+            identity_unicode_table(length)
+        };
+        Ok(Self {
+            metrics: FontMetrics::new(width, height, height),
+            glyphs,
+            unicode_table,
+        })
+    }
+}
+
+impl Font for PsfFont {
+    fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>> {
+        let index = *self.unicode_table.get(grapheme)?;
+        self.glyphs.get(index).map(Cow::Borrowed)
+    }
+
+    fn metrics(&self) -> FontMetrics {
+        self.metrics
+    }
+}
+
+/// An error that occurred while parsing a PSF font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PsfError {
+    /// The data does not start with the PSF1 or PSF2 magic bytes.
+    InvalidMagic,
+    /// The header contains values that are inconsistent or out of range.
+    InvalidHeader,
+    /// The data ended before the font was complete.
+    UnexpectedEof,
+    /// The Unicode table contains an invalid code point or UTF-8 sequence.
+    InvalidUnicodeTable {
+        /// The index of the glyph whose entry is invalid.
+        glyph: usize,
+    },
+}
+
+impl fmt::Display for PsfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "not a PSF1 or PSF2 font"),
+            Self::InvalidHeader => write!(f, "invalid PSF header"),
+            Self::UnexpectedEof => write!(f, "unexpected end of file"),
+            Self::InvalidUnicodeTable { glyph } => {
+                write!(f, "invalid unicode table entry for glyph {glyph}")
+            }
+        }
+    }
+}
+
+impl Error for PsfError {}
+
+/// Maps each glyph index to the code point with the same value.
+fn identity_unicode_table(length: usize) -> HashMap<String, usize> {
+    (0..length)
+        .filter_map(|index| {
+            let c = char::from_u32(u32::try_from(index).ok()?)?;
+            Some((c.to_string(), index))
+        })
+        .collect()
+}
+
+/// Reads a PSF1 unicode table, where each entry is a list of UCS-2 code points followed by an
+/// optional list of sequences, and is terminated by `0xFFFF`.
+fn psf1_unicode_table(
+    reader: &mut Reader,
+    length: usize,
+) -> Result<HashMap<String, usize>, PsfError> {
+    let mut table = HashMap::new();
+    for glyph in 0..length {
+        let invalid = PsfError::InvalidUnicodeTable { glyph };
+        let mut in_sequence = false;
+        let mut sequence = String::new();
+        loop {
+            let value = reader.u16()?;
+            if value == PSF1_SEPARATOR || value == PSF1_START_SEQUENCE {
+                if !sequence.is_empty() {
+                    table.entry(sequence.clone()).or_insert(glyph);
+                    sequence.clear();
+                }
+                if value == PSF1_SEPARATOR {
+                    break;
+                }
+                in_sequence = true;
+                continue;
+            }
+            let c = char::from_u32(value.into()).ok_or(invalid.clone())?;
+            if in_sequence {
+                sequence.push(c);
+            } else {
+                table.entry(c.to_string()).or_insert(glyph);
+            }
+        }
+    }
+    Ok(table)
+}
+
+/// Reads a PSF2 unicode table, where each entry is a UTF-8 string followed by an optional list of
+/// sequences, and is terminated by `0xFF`.
+fn psf2_unicode_table(
+    reader: &mut Reader,
+    length: usize,
+) -> Result<HashMap<String, usize>, PsfError> {
+    let mut table = HashMap::new();
+    for glyph in 0..length {
+        let invalid = PsfError::InvalidUnicodeTable { glyph };
+        let entry = reader.until(PSF2_SEPARATOR)?;
+        let mut parts = entry.split(|&byte| byte == PSF2_START_SEQUENCE);
+        let singles =
+            std::str::from_utf8(parts.next().unwrap_or_default()).map_err(|_| invalid.clone())?;
+        for c in singles.chars() {
+            table.entry(c.to_string()).or_insert(glyph);
+        }
+        for sequence in parts {
+            let sequence = std::str::from_utf8(sequence).map_err(|_| invalid.clone())?;
+            if !sequence.is_empty() {
+                table.entry(sequence.to_string()).or_insert(glyph);
+            }
+        }
+    }
+    Ok(table)
+}
+
+/// A cursor over the bytes of a PSF file.
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn seek(&mut self, position: usize) -> Result<(), PsfError> {
+        if position > self.data.len() {
+            return Err(PsfError::UnexpectedEof);
+        }
+        self.position = position;
+        Ok(())
+    }
+
+    /// The number of bytes after the current position.
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], PsfError> {
+        let end = self.position.saturating_add(count);
+        let bytes = self
+            .data
+            .get(self.position..end)
+            .ok_or(PsfError::UnexpectedEof)?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    /// Reads bytes up to and including the terminator, returning them without the terminator.
+    fn until(&mut self, terminator: u8) -> Result<&'a [u8], PsfError> {
+        let rest = &self.data[self.position..];
+        let length = rest
+            .iter()
+            .position(|&byte| byte == terminator)
+            .ok_or(PsfError::UnexpectedEof)?;
+        self.position += length + 1;
+        Ok(&rest[..length])
+    }
+
+    fn u8(&mut self) -> Result<u8, PsfError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, PsfError> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, PsfError> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads a glyph bitmap, where each row is padded to a whole number of bytes and the most
+    /// significant bit of the first byte is the leftmost pixel.
+    fn glyph(&mut self, width: u16, height: u16) -> Result<Glyph, PsfError> {
+        let row_size = width.div_ceil(8) as usize;
+        let bytes = self.bytes(row_size * height as usize)?;
+        let mut glyph = Glyph::new(width, height);
+        for (y, row) in (0..).zip(bytes.chunks(row_size)) {
+            for x in 0..width {
+                let byte = row[x as usize / 8];
+                glyph.set_pixel(x, y, byte & (0x80 >> (x % 8)) != 0);
+            }
+        }
+        Ok(glyph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::prelude::*;
+
+    use super::*;
+    use crate::BigText;
+
+    type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// A 5x7 "A" and a 5x7 "é", padded to one byte per row.
+    const GLYPH_A: [u8; 7] = [0x70, 0x88, 0x88, 0xF8, 0x88, 0x88, 0x00];
+    const GLYPH_E_ACUTE: [u8; 7] = [0x10, 0x20, 0x70, 0xF8, 0x80, 0x70, 0x00];
+
+    /// Builds a PSF2 font with a 5x7 "A" and "é", where "é" is also mapped from its decomposed
+    /// sequence.
+    fn psf2(with_unicode_table: bool) -> Vec<u8> {
+        let mut data = PSF2_MAGIC.to_vec();
+        let flags = u32::from(with_unicode_table);
+        for value in [0, 32, flags, 2, 7, 7, 5] {
+            data.extend(u32::to_le_bytes(value));
+        }
+        data.extend(GLYPH_A);
+        data.extend(GLYPH_E_ACUTE);
+        if with_unicode_table {
+            data.extend("A".as_bytes());
+            data.push(PSF2_SEPARATOR);
+            data.extend("é".as_bytes());
+            data.push(PSF2_START_SEQUENCE);
+            data.extend("e\u{301}".as_bytes());
+            data.push(PSF2_SEPARATOR);
+        }
+        data
+    }
+
+    /// Builds a PSF1 font with 8x2 glyphs where glyph `n` has the bits of `n` in its first row.
+    fn psf1(mode: u8) -> Vec<u8> {
+        let mut data = PSF1_MAGIC.to_vec();
+        data.extend([mode, 2]);
+        let length = if mode & PSF1_MODE512 != 0 { 512 } else { 256 };
+        for index in 0..length {
+            data.extend([index as u8, 0]);
+        }
+        if mode & PSF1_MODEHASTAB != 0 {
+            for index in 0..length {
+                let code_point = if index == 1 {
+                    'Ω' as u16
+                } else {
+                    index + 0x100
+                };
+                data.extend(code_point.to_le_bytes());
+                if index == 1 {
+                    data.extend(PSF1_START_SEQUENCE.to_le_bytes());
+                    data.extend(('Ω' as u16).to_le_bytes());
+                    data.extend(0x301_u16.to_le_bytes());
+                }
+                data.extend(PSF1_SEPARATOR.to_le_bytes());
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn parse_psf2() -> Result<()> {
+        let font = PsfFont::parse(&psf2(true))?;
+        assert_eq!(font.metrics(), FontMetrics::new(5, 7, 7));
+        assert_eq!(font.len(), 2);
+        let a = font.glyph("A").ok_or("missing glyph")?;
+        assert_eq!(
+            a.rows(),
+            [".###.", "#...#", "#...#", "#####", "#...#", "#...#", "....."]
+        );
+        assert_eq!(font.glyph("é"), font.glyph("e\u{301}"));
+        assert_eq!(font.glyph("B"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_psf2_without_unicode_table() -> Result<()> {
+        let font = PsfFont::parse(&psf2(false))?;
+        assert!(font.glyph("\u{1}").is_some());
+        assert_eq!(font.glyph("A"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_psf1() -> Result<()> {
+        let font = PsfFont::parse(&psf1(0))?;
+        assert_eq!(font.metrics(), FontMetrics::new(8, 2, 2));
+        assert_eq!(font.len(), 256);
+        let glyph = font.glyph("\u{3}").ok_or("missing glyph")?;
+        assert_eq!(glyph.rows(), ["......##", "........"]);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_psf1_with_unicode_table() -> Result<()> {
+        let font = PsfFont::parse(&psf1(PSF1_MODE512 | PSF1_MODEHASTAB))?;
+        assert_eq!(font.len(), 512);
+        let omega = font.glyph("Ω").ok_or("missing glyph")?;
+        assert_eq!(omega.rows(), [".......#", "........"]);
+        assert_eq!(font.glyph("Ω\u{301}"), Some(omega));
+        assert!(font.glyph("\u{1ff}").is_some());
+        assert_eq!(font.glyph("\u{1}"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn render() -> Result<()> {
+        let font = PsfFont::parse(&psf2(true))?;
+        let big_text = BigText::builder()
+            .font(&font)
+            .lines(vec![Line::from("AéA")])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 15, 7));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            " ███    █  ███ ",
+            "█   █  █  █   █",
+            "█   █ ███ █   █",
+            "███████████████",
+            "█   ██    █   █",
+            "█   █ ███ █   █",
+            "               ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() -> Result<()> {
+        assert_eq!(PsfFont::parse(b"nope"), Err(PsfError::InvalidMagic));
+        assert_eq!(
+            PsfFont::parse(&psf1(0)[..100]),
+            Err(PsfError::UnexpectedEof)
+        );
+        let mut data = psf2(true);
+        data[20] = 6; // glyph size doesn't match width and height
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::InvalidHeader));
+        let mut data = psf2(true);
+        let index = data.len() - 2;
+        data[index] = 0xC0; // truncated UTF-8 sequence
+        assert_eq!(
+            PsfFont::parse(&data),
+            Err(PsfError::InvalidUnicodeTable { glyph: 1 })
+        );
+        Ok(())
+    }
+
+    /// Builds a PSF2 header without any glyph data.
+    fn psf2_header(length: u32, glyph_size: u32, height: u32, width: u32) -> Vec<u8> {
+        let mut data = PSF2_MAGIC.to_vec();
+        for value in [0, 32, 0, length, glyph_size, height, width] {
+            data.extend(u32::to_le_bytes(value));
+        }
+        data
+    }
+
+    #[test]
+    fn empty_glyph_size() {
+        let data = psf2_header(1, 0, 8, 0);
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::InvalidHeader));
+        let data = psf2_header(u32::MAX, 0, 0, 8);
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::InvalidHeader));
+        let mut data = psf1(0);
+        data[3] = 0; // charsize
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::InvalidHeader));
+    }
+
+    #[test]
+    fn length_past_end_of_data() {
+        let data = psf2_header(u32::MAX, 7, 7, 5);
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::UnexpectedEof));
+    }
+}
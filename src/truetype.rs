@@ -0,0 +1,236 @@
+use std::{borrow::Cow, error::Error, fmt};
+
+use ab_glyph::{Font as _, FontArc, GlyphId, PxScale, ScaleFont as _};
+
+use crate::font::{Font, FontMetrics, Glyph};
+
+/// The printable ASCII range, used to find the cell width of the font.
+const PRINTABLE_ASCII: std::ops::RangeInclusive<char> = ' '..='~';
+
+/// A TrueType or OpenType font rasterized into bitmap glyphs at a fixed pixel height.
+///
+/// Each glyph outline is rasterized on demand, and every pixel that is covered by at least the
+/// [threshold](Self::with_threshold) fraction of the outline is set. The resulting bitmap is then
+/// drawn with the [`PixelSize`](crate::PixelSize) of the widget like any other font, so a pixel
+/// height of 16 with [`PixelSize::Quadrant`](crate::PixelSize::Quadrant) takes up 8 rows.
+///
+/// The cell width is the widest advance of the printable ASCII characters, and each glyph is as
+/// wide as its own advance. Kerning pairs from the font are applied between glyphs.
+///
+/// This type is only available with the `truetype` feature.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tui_big_text::{BigText, PixelSize, TrueTypeFont};
+///
+/// let data = std::fs::read("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf")?;
+/// let font = TrueTypeFont::parse(&data, 16)?;
+/// let big_text = BigText::builder()
+///     .font(&font)
+///     .pixel_size(PixelSize::Quadrant)
+///     .lines(vec!["Brand".into()])
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrueTypeFont {
+    font: FontArc,
+    scale: PxScale,
+    metrics: FontMetrics,
+    threshold: f32,
+}
+
+impl TrueTypeFont {
+    /// Parses a font from the contents of a `.ttf` or `.otf` file, to be rasterized with glyphs
+    /// that are `pixel_height` pixels tall from the ascender to the descender.
+    pub fn parse(data: &[u8], pixel_height: u16) -> Result<Self, TrueTypeError> {
+        if pixel_height == 0 {
+            return Err(TrueTypeError::InvalidPixelHeight);
+        }
+        let font = FontArc::try_from_vec(data.to_vec()).map_err(|_| TrueTypeError::InvalidFont)?;
+        let scale = PxScale::from(f32::from(pixel_height));
+        let scaled = font.as_scaled(scale);
+        let width = PRINTABLE_ASCII
+            .map(|c| scaled.glyph_id(c))
+            .filter(|id| id.0 != 0)
+            .map(|id| scaled.h_advance(id))
+            .fold(0.0, f32::max)
+            .ceil() as u16;
+        let baseline = scaled.ascent().round().clamp(0.0, f32::from(pixel_height)) as u16;
+        Ok(Self {
+            font,
+            scale,
+            metrics: FontMetrics::new(width, pixel_height, baseline),
+            threshold: 0.5,
+        })
+    }
+
+    /// Sets the fraction of a pixel that must be covered by a glyph outline for the pixel to be
+    /// set.
+    ///
+    /// Lower values make the glyphs bolder and higher values make them thinner. Defaults to `0.5`.
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns the id of the glyph for a single-character grapheme, or `None` if the font has no
+    /// glyph for it.
+    fn glyph_id(&self, grapheme: &str) -> Option<GlyphId> {
+        let mut chars = grapheme.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return None;
+        };
+        let id = self.font.glyph_id(c);
+        (id.0 != 0).then_some(id)
+    }
+}
+
+impl Font for TrueTypeFont {
+    fn glyph(&self, grapheme: &str) -> Option<Cow<'_, Glyph>> {
+        let scaled = self.font.as_scaled(self.scale);
+        let id = self.glyph_id(grapheme)?;
+        let width = scaled.h_advance(id).ceil() as u16;
+        let mut glyph = Glyph::new(width, self.metrics.height);
+        let position = ab_glyph::point(0.0, f32::from(self.metrics.baseline));
+        if let Some(outline) =
+            scaled.outline_glyph(id.with_scale_and_position(self.scale, position))
+        {
+            let bounds = outline.px_bounds();
+            outline.draw(|x, y, coverage| {
+                let x = bounds.min.x as i64 + i64::from(x);
+                let y = bounds.min.y as i64 + i64::from(y);
+                if let (Ok(x), Ok(y)) = (u16::try_from(x), u16::try_from(y)) {
+                    if coverage >= self.threshold {
+                        glyph.set_pixel(x, y, true);
+                    }
+                }
+            });
+        }
+        Some(Cow::Owned(glyph))
+    }
+
+    fn metrics(&self) -> FontMetrics {
+        self.metrics
+    }
+
+    fn kerning(&self, left: &str, right: &str) -> i16 {
+        let (Some(left), Some(right)) = (self.glyph_id(left), self.glyph_id(right)) else {
+            return 0;
+        };
+        self.font.as_scaled(self.scale).kern(left, right).round() as i16
+    }
+}
+
+/// An error that occurred while loading a TrueType or OpenType font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrueTypeError {
+    /// The data is not a valid TrueType or OpenType font.
+    InvalidFont,
+    /// The pixel height is zero.
+    InvalidPixelHeight,
+}
+
+impl fmt::Display for TrueTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFont => write!(f, "not a valid TrueType or OpenType font"),
+            Self::InvalidPixelHeight => write!(f, "pixel height must be greater than zero"),
+        }
+    }
+}
+
+impl Error for TrueTypeError {}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::prelude::*;
+
+    use super::*;
+    use crate::{BigText, PixelSize};
+
+    type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// The demo font from the ttf-parser test suite, which only contains an "A".
+    const DEMO_FONT: &[u8] = include_bytes!("../tests/fonts/demo.ttf");
+
+    #[test]
+    fn parse() -> Result<()> {
+        let font = TrueTypeFont::parse(DEMO_FONT, 14)?;
+        assert_eq!(font.metrics(), FontMetrics::new(6, 14, 10));
+        Ok(())
+    }
+
+    #[test]
+    fn glyph() -> Result<()> {
+        let font = TrueTypeFont::parse(DEMO_FONT, 14)?;
+        let glyph = font.glyph("A").ok_or("missing glyph")?;
+        assert_eq!((glyph.width(), glyph.height()), (6, 14));
+        assert!(font.glyph("B").is_none());
+        assert!(font.glyph("AA").is_none());
+        assert_eq!(font.kerning("A", "A"), 0);
+        assert_eq!(font.kerning("A", "B"), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn threshold() -> Result<()> {
+        let count = |font: &TrueTypeFont| -> Result<usize> {
+            let glyph = font.glyph("A").ok_or("missing glyph")?;
+            Ok((0..glyph.height())
+                .flat_map(|y| (0..glyph.width()).map(move |x| (x, y)))
+                .filter(|&(x, y)| glyph.pixel(x, y))
+                .count())
+        };
+        let font = TrueTypeFont::parse(DEMO_FONT, 14)?;
+        let bold = count(&font.clone().with_threshold(0.1))?;
+        let thin = count(&font.with_threshold(0.9))?;
+        assert!(bold > thin);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            TrueTypeFont::parse(b"not a font", 14).unwrap_err(),
+            TrueTypeError::InvalidFont
+        );
+        assert_eq!(
+            TrueTypeFont::parse(DEMO_FONT, 0).unwrap_err(),
+            TrueTypeError::InvalidPixelHeight
+        );
+    }
+
+    #[test]
+    fn render() -> Result<()> {
+        let font = TrueTypeFont::parse(DEMO_FONT, 14)?.with_threshold(0.25);
+        let big_text = BigText::builder()
+            .font(&font)
+            .pixel_size(PixelSize::Full)
+            .lines(vec!["A".into()])
+            .build()?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 14));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "      ",
+            "      ",
+            "      ",
+            "  █   ",
+            "  ██  ",
+            " ███  ",
+            " █ █  ",
+            " ████ ",
+            "██  █ ",
+            "█   █ ",
+            "      ",
+            "      ",
+            "      ",
+            "      ",
+        ]);
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+/// The vertical alignment of the lines within the area the widget is rendered in.
+///
+/// When the lines are taller than the area, they are always aligned to the top and the lines
+/// that don't fit are cut off at the bottom, like lines that are wider than the area are cut off
+/// on the right.
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_big_text::{BigText, VerticalAlignment};
+///
+/// BigText::builder().vertical_alignment(VerticalAlignment::Center);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VerticalAlignment {
+    /// The first line starts at the top of the area.
+    #[default]
+    Top,
+    /// The lines are centered in the area, with any odd row left empty below them.
+    Center,
+    /// The last line ends at the bottom of the area.
+    Bottom,
+}
+
+impl VerticalAlignment {
+    /// The number of rows between the top of the area and the first line.
+    pub(crate) fn offset(self, area_height: u16, text_height: u16) -> u16 {
+        let space = area_height.saturating_sub(text_height);
+        match self {
+            Self::Top => 0,
+            Self::Center => space / 2,
+            Self::Bottom => space,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset() {
+        assert_eq!(VerticalAlignment::Top.offset(10, 4), 0);
+        assert_eq!(VerticalAlignment::Center.offset(10, 4), 3);
+        assert_eq!(VerticalAlignment::Center.offset(10, 5), 2);
+        assert_eq!(VerticalAlignment::Bottom.offset(10, 4), 6);
+        // text that is taller than the area is aligned to the top
+        assert_eq!(VerticalAlignment::Center.offset(4, 10), 0);
+        assert_eq!(VerticalAlignment::Bottom.offset(4, 10), 0);
+    }
+}